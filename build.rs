@@ -4,4 +4,10 @@ fn main() {
     cc::Build::new()
         .file("wrappers/target.c")
         .compile("llvm_target");
+
+    cc::Build::new()
+        .cpp(true)
+        .file("wrappers/instruction.cpp")
+        .file("wrappers/verifier.cpp")
+        .compile("llvm_wrappers");
 }
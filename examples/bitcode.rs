@@ -0,0 +1,50 @@
+extern crate rlvm;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    Module,
+    VerifierFailureAction,
+    llvm_init,
+};
+use rlvm::types;
+
+fn ir_without_module_id(module: &Module) -> String {
+    module.print_to_string()
+        .lines()
+        .filter(|line| !line.starts_with("; ModuleID"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn main() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    let param_types = [context.int32(), context.int32()];
+    let function_type = types::function::new(context.int32(), &param_types, false);
+    let sum = module.add_function("sum", function_type);
+
+    let entry = BasicBlock::append_in_context(&context, &sum, "entry");
+
+    let builder = Builder::new_in_context(&context);
+    builder.position_at_end(&entry);
+
+    let temp = builder.add(&sum.get_param(0), &sum.get_param(1), "temp");
+    builder.ret(&temp);
+
+    module.verify(VerifierFailureAction::AbortProcess).expect("module verify");
+
+    let buffer = module.write_bitcode_to_memory();
+    println!("Wrote {} bytes of bitcode", buffer.len());
+
+    let parsed_module = context.parse_bitcode(&buffer).expect("parse bitcode");
+    assert_eq!(ir_without_module_id(&module), ir_without_module_id(&parsed_module));
+
+    let lazy_module = context.get_bitcode_module(buffer).expect("lazy bitcode module");
+    assert!(lazy_module.get_named_function("sum").is_some());
+
+    println!("{}", parsed_module);
+}
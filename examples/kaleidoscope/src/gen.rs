@@ -63,10 +63,12 @@ impl Generator {
 
     fn create_entry_block_alloca(&self, function: &Function, variable_name: &str) -> Value {
         let basic_block = function.get_entry_basic_block();
-        let instruction = basic_block.get_first_instruction();
-        let builder = Builder::new_in_context(&self.context);
-        builder.position(&basic_block, &instruction);
-        builder.alloca(self.context.double(), variable_name)
+        let _insertion_point = self.builder.save_insertion_point();
+        match basic_block.get_first_instruction() {
+            Some(instruction) => self.builder.position_before(&instruction),
+            None => self.builder.position_at_end(&basic_block),
+        }
+        self.builder.alloca(self.context.double(), variable_name)
     }
 
     fn expr(&mut self, expr: Expr) -> Result<Value> {
@@ -159,8 +161,8 @@ impl Generator {
                     let merge_basic_block = BasicBlock::append_in_context(&self.context, &function, "ifcont");
                     self.builder.position_at_end(&merge_basic_block);
 
-                    let phi = self.builder.phi(self.context.double(), "result");
-                    phi.add_incoming(&[(&then_value, &new_then_basic_block), (&else_value, &new_else_basic_block)]);
+                    let phi = self.builder.phi_with_incoming(self.context.double(),
+                        &[(&then_value, &new_then_basic_block), (&else_value, &new_else_basic_block)], "result");
 
                     self.builder.position_at_end(&start_basic_block);
                     self.builder.cond_br(&condition, &then_basic_block, &else_basic_block);
@@ -286,7 +288,7 @@ impl Generator {
             };
 
         self.builder.ret(&return_value);
-        llvm_function.verify(VerifierFailureAction::AbortProcess);
+        llvm_function.verify(VerifierFailureAction::AbortProcess).expect("function verify");
 
         self.function_pass_manager.run(&llvm_function);
         //llvm_function.dump();
@@ -1,6 +1,8 @@
 /*
  * Compile the object file with tests/main.c with:
  * gcc tests/main.c output.o -o main
+ *
+ * Pass --emit=llvm-ir to write the textual IR to output.ll instead.
  */
 
 extern crate rlvm;
@@ -12,6 +14,7 @@ mod lexer;
 mod parser;
 
 //use std::fs::File;
+use std::env;
 use std::io::{Write, stdin, stdout};
 
 use rlvm::{
@@ -24,7 +27,6 @@ use rlvm::{
     RelocMode,
     Target,
     get_default_target_triple,
-    initialize_native_target,
     initialize_all_target_infos,
     initialize_all_targets,
     initialize_all_target_mcs,
@@ -53,12 +55,13 @@ pub extern "C" fn putchard(char: f64) -> f64 {
 fn main() -> Result<()> {
     let _llvm = llvm_init();
 
+    let emit_llvm_ir = env::args().skip(1).any(|arg| arg == "--emit=llvm-ir");
+
     initialize_all_target_infos();
     initialize_all_targets();
     initialize_all_target_mcs();
     initialize_all_asm_parsers();
     initialize_all_asm_printers();
-    initialize_native_target();
 
     let target_triple = get_default_target_triple();
     let target = Target::get_from_triple(&target_triple).expect("get target");
@@ -130,9 +133,17 @@ fn main() -> Result<()> {
         print!("ready> ");
         stdout().flush()?;
     }
-    println!("Writing output.o");
-    if let Err(error) = target_machine.emit_to_file(&generator.module, "output.o", CodeGenFileType::ObjectFile) {
-        eprintln!("Cannot emit to object: {}", error);
+    if emit_llvm_ir {
+        println!("Writing output.ll");
+        if let Err(error) = generator.module.print_to_file("output.ll") {
+            eprintln!("Cannot emit LLVM IR: {}", error);
+        }
+    }
+    else {
+        println!("Writing output.o");
+        if let Err(error) = target_machine.emit_to_file(&generator.module, "output.o", CodeGenFileType::ObjectFile) {
+            eprintln!("Cannot emit to object: {}", error);
+        }
     }
     Ok(())
 }
@@ -6,9 +6,6 @@ use rlvm::{
     ExecutionEngine,
     Module,
     VerifierFailureAction,
-    initialize_native_asm_printer,
-    initialize_native_target,
-    link_mcjit,
     llvm_init,
 };
 use rlvm::types;
@@ -16,10 +13,6 @@ use rlvm::types;
 fn main() {
     let _llvm = llvm_init();
 
-    link_mcjit();
-    initialize_native_asm_printer();
-    initialize_native_target();
-
     let module = Module::new_with_name("module");
     let engine = ExecutionEngine::new_for_module(&module).expect("failed to create execution engine");
     let param_types = [types::int32(), types::int32()];
@@ -6,9 +6,6 @@ use rlvm::{
     Context,
     ExecutionEngine,
     VerifierFailureAction,
-    initialize_native_asm_printer,
-    initialize_native_target,
-    link_mcjit,
     llvm_init,
 };
 use rlvm::types;
@@ -16,10 +13,6 @@ use rlvm::types;
 fn main() {
     let _llvm = llvm_init();
 
-    link_mcjit();
-    initialize_native_asm_printer();
-    initialize_native_target();
-
     let context = Context::new();
     let module = context.new_module("module");
     let engine = ExecutionEngine::new_for_module(&module).expect("failed to create execution engine");
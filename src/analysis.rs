@@ -1,12 +1,20 @@
+use std::collections::HashMap;
 use std::ffi::CStr;
+use std::fmt::{self, Display, Formatter};
 use std::ptr;
+use std::slice;
 
 use ffi::{
     LLVMDisposeMessage,
+    LLVMPrintValueToString,
     LLVMVerifierFailureAction,
+    LLVMVerifyFunction,
     LLVMVerifyModule,
+    LLVM_VerifyFunction,
 };
-use module::Module;
+use global_value::GlobalValue;
+use instruction::Instruction;
+use module::{Function, Module};
 
 pub enum VerifierFailureAction {
     AbortProcess,
@@ -24,6 +32,28 @@ impl VerifierFailureAction {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct VerifierDiagnostic {
+    pub function: Option<Function>,
+    pub instruction: Option<Instruction>,
+    pub message: String,
+    // The offending values, as printed by the verifier.
+    pub values: Vec<String>,
+}
+
+impl Display for VerifierDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        if let Some(ref function) = self.function {
+            write!(formatter, "in function {}: ", function.get_name())?;
+        }
+        write!(formatter, "{}", self.message)?;
+        for value in &self.values {
+            write!(formatter, "\n  {}", value)?;
+        }
+        Ok(())
+    }
+}
+
 impl Module {
     pub fn verify(&self, action: VerifierFailureAction) -> Result<(), String> {
         let mut error = ptr::null_mut();
@@ -42,4 +72,113 @@ impl Module {
             }
         }
     }
+
+    // NOTE: this never prints nor aborts.
+    pub fn verify_diagnostics(&self) -> Result<(), Vec<VerifierDiagnostic>> {
+        let message = match self.verify(VerifierFailureAction::ReturnStatus) {
+            Ok(()) => return Ok(()),
+            Err(message) => message,
+        };
+        let functions: Vec<_> = self.functions()
+            .filter(|function| !function.is_declaration())
+            .collect();
+        let mut diagnostics = split_message(&message);
+        attribute_instructions(&mut diagnostics, &functions);
+        Err(diagnostics)
+    }
+}
+
+impl Function {
+    // NOTE: use VerifierFailureAction::ReturnStatus to verify without printing.
+    pub fn verify(&self, action: VerifierFailureAction) -> Result<(), Vec<VerifierDiagnostic>> {
+        let broken = unsafe { LLVMVerifyFunction(self.as_raw(), action.as_raw()) != 0 };
+        if !broken {
+            return Ok(());
+        }
+        let message =
+            unsafe {
+                let mut error = ptr::null_mut();
+                LLVM_VerifyFunction(self.as_raw(), &mut error);
+                let message = CStr::from_ptr(error).to_string_lossy().into_owned();
+                LLVMDisposeMessage(error);
+                message
+            };
+        let mut diagnostics = split_message(&message);
+        attribute_instructions(&mut diagnostics, slice::from_ref(self));
+        for diagnostic in &mut diagnostics {
+            diagnostic.function = Some(self.clone());
+        }
+        Err(diagnostics)
+    }
+}
+
+// The verifier prints each message on its own line, followed by the offending values. Instructions
+// are printed indented, but other values cannot always be told apart from the messages (e.g. types
+// are printed without a newline), so this split is a best effort.
+fn split_message(message: &str) -> Vec<VerifierDiagnostic> {
+    let mut diagnostics: Vec<VerifierDiagnostic> = vec![];
+    for line in message.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let is_value = line.starts_with(char::is_whitespace) || line.starts_with(|c| "%@!".contains(c));
+        match diagnostics.last_mut() {
+            Some(ref mut diagnostic) if is_value => diagnostic.values.push(line.trim().to_string()),
+            _ => diagnostics.push(VerifierDiagnostic {
+                function: None,
+                instruction: None,
+                message: line.trim().to_string(),
+                values: vec![],
+            }),
+        }
+    }
+    diagnostics
+}
+
+// Attribute the diagnostics to the instruction printed in their values, when it can be found in
+// exactly one place.
+fn attribute_instructions(diagnostics: &mut [VerifierDiagnostic], functions: &[Function]) {
+    let mut instructions: HashMap<String, Option<(Function, Instruction)>> = HashMap::new();
+    for function in functions {
+        for (text, instruction) in instruction_lines(function) {
+            instructions.entry(text)
+                .and_modify(|entry| *entry = None)
+                .or_insert_with(|| Some((function.clone(), instruction)));
+        }
+    }
+
+    for diagnostic in diagnostics {
+        let found = diagnostic.values.iter()
+            .filter_map(|value| instructions.get(value).cloned().and_then(|entry| entry))
+            .next();
+        if let Some((function, instruction)) = found {
+            diagnostic.function = Some(function);
+            diagnostic.instruction = Some(instruction);
+        }
+    }
+}
+
+// Print the function once and pair its instructions with their lines, instead of printing each
+// instruction, which numbers the whole function every time.
+fn instruction_lines(function: &Function) -> Vec<(String, Instruction)> {
+    let text =
+        unsafe {
+            let string = LLVMPrintValueToString(function.as_raw());
+            let text = CStr::from_ptr(string).to_string_lossy().into_owned();
+            LLVMDisposeMessage(string);
+            text
+        };
+    // Instructions start with two spaces; the other indented lines continue an instruction (e.g. the
+    // cases of a switch).
+    let lines: Vec<_> = text.lines()
+        .filter(|line| line.starts_with("  ") && !line[2..].starts_with(|c: char| c.is_whitespace() || c == ']'))
+        .map(|line| line.trim().to_string())
+        .collect();
+    let instructions: Vec<_> = function.basic_blocks()
+        .flat_map(|block| block.instructions().collect::<Vec<_>>())
+        .collect();
+    if lines.len() != instructions.len() {
+        return vec![];
+    }
+    lines.into_iter().zip(instructions).collect()
 }
@@ -0,0 +1,408 @@
+use std::os::raw::{c_char, c_uint};
+use std::ptr;
+
+use Context;
+use ffi::{
+    LLVMAddAttributeAtIndex,
+    LLVMAddCallSiteAttribute,
+    LLVMAttributeIndex,
+    LLVMAttributeRef,
+    LLVMCreateEnumAttribute,
+    LLVMCreateStringAttribute,
+    LLVMCreateTypeAttribute,
+    LLVMGetAttributeCountAtIndex,
+    LLVMGetAttributesAtIndex,
+    LLVMGetCallSiteAttributeCount,
+    LLVMGetCallSiteAttributes,
+    LLVMGetCallSiteEnumAttribute,
+    LLVMGetCallSiteStringAttribute,
+    LLVMGetEnumAttributeAtIndex,
+    LLVMGetEnumAttributeKind,
+    LLVMGetEnumAttributeKindForName,
+    LLVMGetEnumAttributeValue,
+    LLVMGetStringAttributeAtIndex,
+    LLVMGetStringAttributeKind,
+    LLVMGetStringAttributeValue,
+    LLVMGetTypeAttributeValue,
+    LLVMIsEnumAttribute,
+    LLVMIsStringAttribute,
+    LLVMIsTypeAttribute,
+    LLVMRemoveCallSiteEnumAttribute,
+    LLVMRemoveCallSiteStringAttribute,
+    LLVMRemoveEnumAttributeAtIndex,
+    LLVMRemoveStringAttributeAtIndex,
+};
+use instruction::Instruction;
+use module::Function;
+use string_from_raw_parts;
+use types::Type;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributeLoc {
+    Function,
+    Return,
+    Param(usize),
+}
+
+impl AttributeLoc {
+    fn as_raw(&self) -> LLVMAttributeIndex {
+        match *self {
+            AttributeLoc::Function => LLVMAttributeIndex::MAX,
+            AttributeLoc::Return => 0,
+            AttributeLoc::Param(index) => index as LLVMAttributeIndex + 1,
+        }
+    }
+}
+
+pub trait AttributeKind {
+    fn name(&self) -> &'static str;
+
+    fn kind_id(&self) -> c_uint {
+        let name = self.name();
+        let kind_id = unsafe { LLVMGetEnumAttributeKindForName(name.as_ptr() as *const c_char, name.len()) };
+        assert_ne!(kind_id, 0, "unknown attribute {}", name);
+        kind_id
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnumAttribute {
+    AlwaysInline,
+    ArgMemOnly,
+    Builtin,
+    Cold,
+    Convergent,
+    Hot,
+    InaccessibleMemOnly,
+    InlineHint,
+    InReg,
+    MinSize,
+    MustProgress,
+    Naked,
+    Nest,
+    NoAlias,
+    NoBuiltin,
+    NoCapture,
+    NoDuplicate,
+    NoFree,
+    NoImplicitFloat,
+    NoInline,
+    NoMerge,
+    NonLazyBind,
+    NonNull,
+    NoRecurse,
+    NoRedZone,
+    NoReturn,
+    NoSync,
+    NoUndef,
+    NoUnwind,
+    OptimizeForSize,
+    OptimizeNone,
+    ReadNone,
+    ReadOnly,
+    Returned,
+    ReturnsTwice,
+    SafeStack,
+    SExt,
+    Speculatable,
+    StackProtect,
+    StackProtectReq,
+    StackProtectStrong,
+    WillReturn,
+    WriteOnly,
+    ZExt,
+}
+
+impl AttributeKind for EnumAttribute {
+    fn name(&self) -> &'static str {
+        match *self {
+            EnumAttribute::AlwaysInline => "alwaysinline",
+            EnumAttribute::ArgMemOnly => "argmemonly",
+            EnumAttribute::Builtin => "builtin",
+            EnumAttribute::Cold => "cold",
+            EnumAttribute::Convergent => "convergent",
+            EnumAttribute::Hot => "hot",
+            EnumAttribute::InaccessibleMemOnly => "inaccessiblememonly",
+            EnumAttribute::InlineHint => "inlinehint",
+            EnumAttribute::InReg => "inreg",
+            EnumAttribute::MinSize => "minsize",
+            EnumAttribute::MustProgress => "mustprogress",
+            EnumAttribute::Naked => "naked",
+            EnumAttribute::Nest => "nest",
+            EnumAttribute::NoAlias => "noalias",
+            EnumAttribute::NoBuiltin => "nobuiltin",
+            EnumAttribute::NoCapture => "nocapture",
+            EnumAttribute::NoDuplicate => "noduplicate",
+            EnumAttribute::NoFree => "nofree",
+            EnumAttribute::NoImplicitFloat => "noimplicitfloat",
+            EnumAttribute::NoInline => "noinline",
+            EnumAttribute::NoMerge => "nomerge",
+            EnumAttribute::NonLazyBind => "nonlazybind",
+            EnumAttribute::NonNull => "nonnull",
+            EnumAttribute::NoRecurse => "norecurse",
+            EnumAttribute::NoRedZone => "noredzone",
+            EnumAttribute::NoReturn => "noreturn",
+            EnumAttribute::NoSync => "nosync",
+            EnumAttribute::NoUndef => "noundef",
+            EnumAttribute::NoUnwind => "nounwind",
+            EnumAttribute::OptimizeForSize => "optsize",
+            EnumAttribute::OptimizeNone => "optnone",
+            EnumAttribute::ReadNone => "readnone",
+            EnumAttribute::ReadOnly => "readonly",
+            EnumAttribute::Returned => "returned",
+            EnumAttribute::ReturnsTwice => "returns_twice",
+            EnumAttribute::SafeStack => "safestack",
+            EnumAttribute::SExt => "signext",
+            EnumAttribute::Speculatable => "speculatable",
+            EnumAttribute::StackProtect => "ssp",
+            EnumAttribute::StackProtectReq => "sspreq",
+            EnumAttribute::StackProtectStrong => "sspstrong",
+            EnumAttribute::WillReturn => "willreturn",
+            EnumAttribute::WriteOnly => "writeonly",
+            EnumAttribute::ZExt => "zeroext",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IntAttribute {
+    Align,
+    AlignStack,
+    Dereferenceable,
+    DereferenceableOrNull,
+}
+
+impl AttributeKind for IntAttribute {
+    fn name(&self) -> &'static str {
+        match *self {
+            IntAttribute::Align => "align",
+            IntAttribute::AlignStack => "alignstack",
+            IntAttribute::Dereferenceable => "dereferenceable",
+            IntAttribute::DereferenceableOrNull => "dereferenceable_or_null",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TypeAttribute {
+    ByRef,
+    ByVal,
+    ElementType,
+    InAlloca,
+    Preallocated,
+    StructRet,
+}
+
+impl AttributeKind for TypeAttribute {
+    fn name(&self) -> &'static str {
+        match *self {
+            TypeAttribute::ByRef => "byref",
+            TypeAttribute::ByVal => "byval",
+            TypeAttribute::ElementType => "elementtype",
+            TypeAttribute::InAlloca => "inalloca",
+            TypeAttribute::Preallocated => "preallocated",
+            TypeAttribute::StructRet => "sret",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute(LLVMAttributeRef);
+
+impl Attribute {
+    /// # Safety
+    ///
+    /// `attribute` must be a valid attribute.
+    pub unsafe fn from_raw(attribute: LLVMAttributeRef) -> Self {
+        Self(attribute)
+    }
+
+    pub fn as_raw(&self) -> LLVMAttributeRef {
+        self.0
+    }
+
+    pub fn get_enum_kind_id(&self) -> c_uint {
+        debug_assert!(self.is_enum(), "call get_enum_kind_id() on an enum attribute");
+        unsafe {
+            LLVMGetEnumAttributeKind(self.as_raw())
+        }
+    }
+
+    pub fn get_enum_value(&self) -> u64 {
+        debug_assert!(self.is_enum(), "call get_enum_value() on an enum attribute");
+        unsafe {
+            LLVMGetEnumAttributeValue(self.as_raw())
+        }
+    }
+
+    pub fn get_string_kind(&self) -> String {
+        debug_assert!(self.is_string(), "call get_string_kind() on a string attribute");
+        unsafe {
+            let mut len = 0;
+            let kind = LLVMGetStringAttributeKind(self.as_raw(), &mut len);
+            string_from_raw_parts(kind, len as usize)
+        }
+    }
+
+    pub fn get_string_value(&self) -> String {
+        debug_assert!(self.is_string(), "call get_string_value() on a string attribute");
+        unsafe {
+            let mut len = 0;
+            let value = LLVMGetStringAttributeValue(self.as_raw(), &mut len);
+            string_from_raw_parts(value, len as usize)
+        }
+    }
+
+    pub fn get_type_value(&self) -> Type {
+        debug_assert!(self.is_type(), "call get_type_value() on a type attribute");
+        unsafe {
+            Type::from_raw(LLVMGetTypeAttributeValue(self.as_raw()))
+        }
+    }
+
+    pub fn is_enum(&self) -> bool {
+        unsafe {
+            LLVMIsEnumAttribute(self.as_raw()) != 0
+        }
+    }
+
+    pub fn is_string(&self) -> bool {
+        unsafe {
+            LLVMIsStringAttribute(self.as_raw()) != 0
+        }
+    }
+
+    pub fn is_type(&self) -> bool {
+        unsafe {
+            LLVMIsTypeAttribute(self.as_raw()) != 0
+        }
+    }
+}
+
+fn attribute_or_none(attribute: LLVMAttributeRef) -> Option<Attribute> {
+    if attribute.is_null() {
+        None
+    }
+    else {
+        Some(Attribute(attribute))
+    }
+}
+
+impl Context {
+    pub fn enum_attribute(&self, kind: EnumAttribute) -> Attribute {
+        unsafe {
+            Attribute(LLVMCreateEnumAttribute(self.as_raw(), kind.kind_id(), 0))
+        }
+    }
+
+    pub fn int_attribute(&self, kind: IntAttribute, value: u64) -> Attribute {
+        unsafe {
+            Attribute(LLVMCreateEnumAttribute(self.as_raw(), kind.kind_id(), value))
+        }
+    }
+
+    pub fn string_attribute(&self, kind: &str, value: &str) -> Attribute {
+        unsafe {
+            Attribute(LLVMCreateStringAttribute(self.as_raw(), kind.as_ptr() as *const c_char, kind.len() as c_uint,
+                value.as_ptr() as *const c_char, value.len() as c_uint))
+        }
+    }
+
+    pub fn type_attribute(&self, kind: TypeAttribute, typ: &Type) -> Attribute {
+        unsafe {
+            Attribute(LLVMCreateTypeAttribute(self.as_raw(), kind.kind_id(), typ.as_raw()))
+        }
+    }
+}
+
+impl Function {
+    pub fn add_attribute(&self, loc: AttributeLoc, attribute: &Attribute) {
+        unsafe {
+            LLVMAddAttributeAtIndex(self.as_raw(), loc.as_raw(), attribute.as_raw());
+        }
+    }
+
+    pub fn attribute_count(&self, loc: AttributeLoc) -> usize {
+        unsafe {
+            LLVMGetAttributeCountAtIndex(self.as_raw(), loc.as_raw()) as usize
+        }
+    }
+
+    pub fn get_attributes(&self, loc: AttributeLoc) -> Vec<Attribute> {
+        let mut attributes = vec![ptr::null_mut(); self.attribute_count(loc)];
+        unsafe {
+            LLVMGetAttributesAtIndex(self.as_raw(), loc.as_raw(), attributes.as_mut_ptr());
+        }
+        attributes.into_iter().map(Attribute).collect()
+    }
+
+    pub fn get_enum_attribute<K: AttributeKind>(&self, loc: AttributeLoc, kind: K) -> Option<Attribute> {
+        unsafe {
+            attribute_or_none(LLVMGetEnumAttributeAtIndex(self.as_raw(), loc.as_raw(), kind.kind_id()))
+        }
+    }
+
+    pub fn get_string_attribute(&self, loc: AttributeLoc, kind: &str) -> Option<Attribute> {
+        unsafe {
+            attribute_or_none(LLVMGetStringAttributeAtIndex(self.as_raw(), loc.as_raw(), kind.as_ptr() as *const c_char, kind.len() as c_uint))
+        }
+    }
+
+    pub fn remove_enum_attribute<K: AttributeKind>(&self, loc: AttributeLoc, kind: K) {
+        unsafe {
+            LLVMRemoveEnumAttributeAtIndex(self.as_raw(), loc.as_raw(), kind.kind_id());
+        }
+    }
+
+    pub fn remove_string_attribute(&self, loc: AttributeLoc, kind: &str) {
+        unsafe {
+            LLVMRemoveStringAttributeAtIndex(self.as_raw(), loc.as_raw(), kind.as_ptr() as *const c_char, kind.len() as c_uint);
+        }
+    }
+}
+
+impl Instruction {
+    pub fn add_call_site_attribute(&self, loc: AttributeLoc, attribute: &Attribute) {
+        unsafe {
+            LLVMAddCallSiteAttribute(self.as_raw(), loc.as_raw(), attribute.as_raw());
+        }
+    }
+
+    pub fn call_site_attribute_count(&self, loc: AttributeLoc) -> usize {
+        unsafe {
+            LLVMGetCallSiteAttributeCount(self.as_raw(), loc.as_raw()) as usize
+        }
+    }
+
+    pub fn get_call_site_attributes(&self, loc: AttributeLoc) -> Vec<Attribute> {
+        let mut attributes = vec![ptr::null_mut(); self.call_site_attribute_count(loc)];
+        unsafe {
+            LLVMGetCallSiteAttributes(self.as_raw(), loc.as_raw(), attributes.as_mut_ptr());
+        }
+        attributes.into_iter().map(Attribute).collect()
+    }
+
+    pub fn get_call_site_enum_attribute<K: AttributeKind>(&self, loc: AttributeLoc, kind: K) -> Option<Attribute> {
+        unsafe {
+            attribute_or_none(LLVMGetCallSiteEnumAttribute(self.as_raw(), loc.as_raw(), kind.kind_id()))
+        }
+    }
+
+    pub fn get_call_site_string_attribute(&self, loc: AttributeLoc, kind: &str) -> Option<Attribute> {
+        unsafe {
+            attribute_or_none(LLVMGetCallSiteStringAttribute(self.as_raw(), loc.as_raw(), kind.as_ptr() as *const c_char, kind.len() as c_uint))
+        }
+    }
+
+    pub fn remove_call_site_enum_attribute<K: AttributeKind>(&self, loc: AttributeLoc, kind: K) {
+        unsafe {
+            LLVMRemoveCallSiteEnumAttribute(self.as_raw(), loc.as_raw(), kind.kind_id());
+        }
+    }
+
+    pub fn remove_call_site_string_attribute(&self, loc: AttributeLoc, kind: &str) {
+        unsafe {
+            LLVMRemoveCallSiteStringAttribute(self.as_raw(), loc.as_raw(), kind.as_ptr() as *const c_char, kind.len() as c_uint);
+        }
+    }
+}
@@ -1,17 +1,34 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 
-use {Context, Value};
+use {Context, Instruction, Value};
 use ffi::{
     LLVMAppendBasicBlock,
     LLVMAppendBasicBlockInContext,
+    LLVMBasicBlockAsValue,
     LLVMBasicBlockRef,
     LLVMCreateBasicBlockInContext,
+    LLVMDeleteBasicBlock,
+    LLVMGetBasicBlockName,
     LLVMGetBasicBlockParent,
+    LLVMGetBasicBlockTerminator,
     LLVMGetFirstInstruction,
+    LLVMGetFirstUse,
+    LLVMGetLastInstruction,
+    LLVMGetNextInstruction,
+    LLVMGetNextUse,
+    LLVMGetUser,
+    LLVMInsertBasicBlock,
+    LLVMInsertBasicBlockInContext,
+    LLVMMoveBasicBlockAfter,
+    LLVMMoveBasicBlockBefore,
+    LLVMRemoveBasicBlockFromParent,
+    LLVMSetValueName2,
+    LLVMValueRef,
 };
-use module::Function;
+use module::{Function, ModuleIter};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct BasicBlock(LLVMBasicBlockRef);
 
 impl BasicBlock {
@@ -29,6 +46,20 @@ impl BasicBlock {
         }
     }
 
+    pub fn insert_before(basic_block: &BasicBlock, name: &str) -> Self {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Self(LLVMInsertBasicBlock(basic_block.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn insert_before_in_context(context: &Context, basic_block: &BasicBlock, name: &str) -> Self {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Self(LLVMInsertBasicBlockInContext(context.as_raw(), basic_block.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn new_in_context(context: &Context, name: &str) -> Self {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -40,9 +71,27 @@ impl BasicBlock {
         Self(basic_block)
     }
 
-    pub fn get_first_instruction(&self) -> Value {
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(LLVMBasicBlockAsValue(self.as_raw()))
+        }
+    }
+
+    pub fn delete(self) {
+        unsafe {
+            LLVMDeleteBasicBlock(self.as_raw());
+        }
+    }
+
+    pub fn get_first_instruction(&self) -> Option<Instruction> {
+        unsafe {
+            instruction_from_raw(LLVMGetFirstInstruction(self.as_raw()))
+        }
+    }
+
+    pub fn get_name(&self) -> String {
         unsafe {
-            Value::from_raw(LLVMGetFirstInstruction(self.as_raw()))
+            CStr::from_ptr(LLVMGetBasicBlockName(self.as_raw())).to_str().expect("to_str").to_string()
         }
     }
 
@@ -52,7 +101,80 @@ impl BasicBlock {
         }
     }
 
+    pub fn instructions(&self) -> ModuleIter<'_, Instruction> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstInstruction(self.as_raw()), LLVMGetNextInstruction, Instruction::from_raw)
+        }
+    }
+
+    pub fn last_instruction(&self) -> Option<Instruction> {
+        unsafe {
+            instruction_from_raw(LLVMGetLastInstruction(self.as_raw()))
+        }
+    }
+
+    pub fn move_after(&self, basic_block: &BasicBlock) {
+        unsafe {
+            LLVMMoveBasicBlockAfter(self.as_raw(), basic_block.as_raw());
+        }
+    }
+
+    pub fn move_before(&self, basic_block: &BasicBlock) {
+        unsafe {
+            LLVMMoveBasicBlockBefore(self.as_raw(), basic_block.as_raw());
+        }
+    }
+
+    // NOTE: the predecessors are the parents of the terminators using this block.
+    pub fn predecessors(&self) -> Vec<BasicBlock> {
+        let mut predecessors = vec![];
+        unsafe {
+            let mut usage = LLVMGetFirstUse(LLVMBasicBlockAsValue(self.as_raw()));
+            while !usage.is_null() {
+                let user = Value::from_raw(LLVMGetUser(usage));
+                if let Some(parent) = user.as_instruction().filter(Instruction::is_terminator).and_then(|user| user.parent()) {
+                    if !predecessors.contains(&parent) {
+                        predecessors.push(parent);
+                    }
+                }
+                usage = LLVMGetNextUse(usage);
+            }
+        }
+        predecessors
+    }
+
+    pub fn remove_from_parent(&self) {
+        unsafe {
+            LLVMRemoveBasicBlockFromParent(self.as_raw());
+        }
+    }
+
+    pub fn set_name(&self, name: &str) {
+        unsafe {
+            LLVMSetValueName2(LLVMBasicBlockAsValue(self.as_raw()), name.as_ptr() as *const c_char, name.len());
+        }
+    }
+
+    pub fn successors(&self) -> Vec<BasicBlock> {
+        self.terminator()
+            .map(|terminator| terminator.successors())
+            .unwrap_or_default()
+    }
+
+    pub fn terminator(&self) -> Option<Instruction> {
+        unsafe {
+            instruction_from_raw(LLVMGetBasicBlockTerminator(self.as_raw()))
+        }
+    }
+
     pub fn as_raw(&self) -> LLVMBasicBlockRef {
         self.0
     }
 }
+
+unsafe fn instruction_from_raw(instruction: LLVMValueRef) -> Option<Instruction> {
+    if instruction.is_null() {
+        return None;
+    }
+    Some(Instruction::from_raw(instruction))
+}
@@ -0,0 +1,69 @@
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::ptr;
+
+use Context;
+use error::{Error, Result};
+use ffi::{
+    LLVMDisposeMessage,
+    LLVMGetBitcodeModuleInContext,
+    LLVMParseBitcodeInContext,
+    LLVMWriteBitcodeToFile,
+    LLVMWriteBitcodeToMemoryBuffer,
+};
+use memory_buffer::MemoryBuffer;
+use module::Module;
+
+impl Context {
+    // NOTE: the functions are materialized on demand, so the module owns the buffer.
+    pub fn get_bitcode_module(&self, buffer: MemoryBuffer) -> Result<Module> {
+        let mut module = ptr::null_mut();
+        let mut error = ptr::null_mut();
+        unsafe {
+            if LLVMGetBitcodeModuleInContext(self.as_raw(), buffer.as_raw(), &mut module, &mut error) != 0 {
+                let cstr = CStr::from_ptr(error);
+                let parse_error = cstr.to_str().expect("error cstr").to_string();
+                LLVMDisposeMessage(error);
+                Err(Error::ParseBitcode(parse_error))
+            }
+            else {
+                mem::forget(buffer);
+                Ok(Module::from_raw(module))
+            }
+        }
+    }
+
+    pub fn parse_bitcode(&self, buffer: &MemoryBuffer) -> Result<Module> {
+        let mut module = ptr::null_mut();
+        let mut error = ptr::null_mut();
+        unsafe {
+            if LLVMParseBitcodeInContext(self.as_raw(), buffer.as_raw(), &mut module, &mut error) != 0 {
+                let cstr = CStr::from_ptr(error);
+                let parse_error = cstr.to_str().expect("error cstr").to_string();
+                LLVMDisposeMessage(error);
+                Err(Error::ParseBitcode(parse_error))
+            }
+            else {
+                Ok(Module::from_raw(module))
+            }
+        }
+    }
+}
+
+impl Module {
+    pub fn write_bitcode_to_file(&self, filename: &str) -> Result<()> {
+        let cstring = CString::new(filename).expect("cstring");
+        unsafe {
+            if LLVMWriteBitcodeToFile(self.as_raw(), cstring.as_ptr()) != 0 {
+                return Err(Error::WriteBitcode(filename.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_bitcode_to_memory(&self) -> MemoryBuffer {
+        unsafe {
+            MemoryBuffer::from_raw(LLVMWriteBitcodeToMemoryBuffer(self.as_raw()))
+        }
+    }
+}
@@ -1,46 +1,78 @@
+use std::cell::Cell;
 use std::ffi::CString;
 use std::os::raw::c_uint;
 use std::ptr;
 
-use Context;
+use {Context, Instruction, VerifierFailureAction};
 use basic_block::BasicBlock;
 use ffi::{
     LLVMBuildAdd,
     LLVMBuildAlloca,
     LLVMBuildAnd,
+    LLVMBuildAShr,
     LLVMBuildBitCast,
     LLVMBuildBr,
     LLVMBuildCall,
     LLVMBuildCondBr,
+    LLVMBuilderRef,
+    LLVMBuildExtractElement,
     LLVMBuildFAdd,
     LLVMBuildFCmp,
+    LLVMBuildFDiv,
     LLVMBuildFMul,
+    LLVMBuildFNeg,
+    LLVMBuildFPExt,
+    LLVMBuildFPToSI,
+    LLVMBuildFPToUI,
+    LLVMBuildFPTrunc,
+    LLVMBuildFRem,
     LLVMBuildFSub,
     LLVMBuildGEP2,
     LLVMBuildGlobalStringPtr,
     LLVMBuildICmp,
+    LLVMBuildInsertElement,
+    LLVMBuildIntToPtr,
     LLVMBuildLoad2,
+    LLVMBuildLShr,
     LLVMBuildMemMove,
     LLVMBuildMemSet,
     LLVMBuildMul,
+    LLVMBuildNeg,
+    LLVMBuildNot,
     LLVMBuildOr,
     LLVMBuildPhi,
-    LLVMBuilderRef,
+    LLVMBuildPtrToInt,
     LLVMBuildRet,
     LLVMBuildSDiv,
+    LLVMBuildSExt,
+    LLVMBuildShl,
+    LLVMBuildSIToFP,
+    LLVMBuildSRem,
     LLVMBuildStore,
     LLVMBuildStructGEP2,
     LLVMBuildSub,
+    LLVMBuildTrunc,
+    LLVMBuildUDiv,
     LLVMBuildUIToFP,
+    LLVMBuildURem,
+    LLVMBuildXor,
+    LLVMBuildZExt,
+    LLVMClearInsertionPosition,
     LLVMCreateBuilder,
     LLVMCreateBuilderInContext,
     LLVMDisposeBuilder,
+    LLVMGetCurrentDebugLocation2,
     LLVMGetInsertBlock,
+    LLVMInsertIntoBuilderWithName,
     LLVMIntPredicate,
     LLVMPositionBuilder,
     LLVMPositionBuilderAtEnd,
+    LLVMPositionBuilderBefore,
     LLVMRealPredicate,
+    LLVMSetCurrentDebugLocation2,
+    LLVMValueRef,
 };
+use metadata::Metadata;
 use module::Function;
 use types::{self, Type};
 use value::Value;
@@ -117,16 +149,30 @@ impl RealPredicate {
     }
 }
 
-pub struct Builder(LLVMBuilderRef);
+pub struct Builder {
+    builder: LLVMBuilderRef,
+    // NOTE: LLVM does not expose the insertion point within the block, so it is tracked here to be
+    // able to restore it. A null instruction means the end of the block.
+    insert_before: Cell<LLVMValueRef>,
+    debug_verify: Cell<bool>,
+}
 
 impl Builder {
     pub fn new() -> Self {
-        unsafe { Builder(LLVMCreateBuilder()) }
+        unsafe { Builder::from_raw(LLVMCreateBuilder()) }
     }
 
     pub fn new_in_context(context: &Context) -> Self {
         unsafe {
-            Builder(LLVMCreateBuilderInContext(context.as_raw()))
+            Builder::from_raw(LLVMCreateBuilderInContext(context.as_raw()))
+        }
+    }
+
+    unsafe fn from_raw(builder: LLVMBuilderRef) -> Self {
+        Builder {
+            builder,
+            insert_before: Cell::new(ptr::null_mut()),
+            debug_verify: Cell::new(false),
         }
     }
 
@@ -153,8 +199,15 @@ impl Builder {
         }
     }
 
+    pub fn ashr(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildAShr(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn as_raw(&self) -> LLVMBuilderRef {
-        self.0
+        self.builder
     }
 
     pub fn bitcast(&self, value: &Value, dest_type: Type, name: &str) -> Value {
@@ -170,6 +223,13 @@ impl Builder {
         }
     }
 
+    pub fn clear_insertion_position(&self) {
+        self.insert_before.set(ptr::null_mut());
+        unsafe {
+            LLVMClearInsertionPosition(self.as_raw());
+        }
+    }
+
     pub fn call(&self, func: Function, args: &[Value], name: &str) -> Value {
         assert_eq!(func.param_count(), args.len(), "wrong number of argument for function call");
         let cstring = CString::new(name).expect("cstring");
@@ -191,6 +251,13 @@ impl Builder {
         }
     }
 
+    pub fn extract_element(&self, vector: &Value, index: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildExtractElement(self.as_raw(), vector.as_raw(), index.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn fadd(&self, op1: &Value, op2: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -205,6 +272,27 @@ impl Builder {
         }
     }
 
+    pub fn fdiv(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFDiv(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    // NOTE: call this once the function is complete (e.g. after finalizing its SsaBuilder). It panics
+    // with the verifier diagnostics if the function is invalid and debug verification is enabled.
+    pub fn finish_function(&self, function: &Function) {
+        if !self.debug_verify.get() {
+            return;
+        }
+        if let Err(diagnostics) = function.verify(VerifierFailureAction::ReturnStatus) {
+            let messages: Vec<_> = diagnostics.iter()
+                .map(ToString::to_string)
+                .collect();
+            panic!("function {} is invalid:\n{}", function.get_name(), messages.join("\n"));
+        }
+    }
+
     pub fn icmp(&self, predicate: IntPredicate, op1: &Value, op2: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -212,6 +300,34 @@ impl Builder {
         }
     }
 
+    pub fn floating_point_extend(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFPExt(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn floating_point_to_signed_int(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFPToSI(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn floating_point_to_unsigned_int(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFPToUI(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn floating_point_trunc(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFPTrunc(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn fmul(&self, op1: &Value, op2: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -219,6 +335,20 @@ impl Builder {
         }
     }
 
+    pub fn fneg(&self, value: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFNeg(self.as_raw(), value.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn frem(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildFRem(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn fsub(&self, op1: &Value, op2: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -234,6 +364,16 @@ impl Builder {
         }
     }
 
+    pub fn get_current_debug_location(&self) -> Option<Metadata> {
+        unsafe {
+            let location = LLVMGetCurrentDebugLocation2(self.as_raw());
+            if location.is_null() {
+                return None;
+            }
+            Some(Metadata::from_raw(location))
+        }
+    }
+
     pub fn get_insert_block(&self) -> Option<BasicBlock> {
         unsafe {
             let basic_block = LLVMGetInsertBlock(self.as_raw());
@@ -253,6 +393,27 @@ impl Builder {
         }
     }
 
+    pub fn insert_element(&self, vector: &Value, element: &Value, index: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildInsertElement(self.as_raw(), vector.as_raw(), element.as_raw(), index.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn insert_into_builder(&self, instruction: &Instruction, name: &str) {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            LLVMInsertIntoBuilderWithName(self.as_raw(), instruction.as_raw(), cstring.as_ptr());
+        }
+    }
+
+    pub fn int_to_ptr(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildIntToPtr(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn load(&self, typ: Type, value: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -260,6 +421,13 @@ impl Builder {
         }
     }
 
+    pub fn lshr(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildLShr(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn mem_move(&self, dest: &Value, dest_align: usize, src: &Value, src_align: usize, size: &Value) -> Value {
         debug_assert!(src_align == 0 || src_align.is_power_of_two());
         debug_assert!(dest_align == 0 || dest_align.is_power_of_two());
@@ -281,6 +449,20 @@ impl Builder {
         }
     }
 
+    pub fn neg(&self, value: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildNeg(self.as_raw(), value.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn not(&self, value: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildNot(self.as_raw(), value.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn or(&self, left: &Value, right: &Value, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -295,18 +477,40 @@ impl Builder {
         }
     }
 
+    pub fn phi_with_incoming(&self, typ: Type, incoming: &[(&Value, &BasicBlock)], name: &str) -> Value {
+        let phi = self.phi(typ, name);
+        phi.add_incoming(incoming);
+        phi
+    }
+
     pub fn position(&self, block: &BasicBlock, instruction: &Value) {
+        self.insert_before.set(instruction.as_raw());
         unsafe {
             LLVMPositionBuilder(self.as_raw(), block.as_raw(), instruction.as_raw());
         }
     }
 
     pub fn position_at_end(&self, entry: &BasicBlock) {
+        self.insert_before.set(ptr::null_mut());
         unsafe {
             LLVMPositionBuilderAtEnd(self.as_raw(), entry.as_raw());
         }
     }
 
+    pub fn position_before(&self, instruction: &Instruction) {
+        self.insert_before.set(instruction.as_raw());
+        unsafe {
+            LLVMPositionBuilderBefore(self.as_raw(), instruction.as_raw());
+        }
+    }
+
+    pub fn ptr_to_int(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildPtrToInt(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn ret(&self, value: &Value) -> Value {
         unsafe {
             Value::from_raw(LLVMBuildRet(self.as_raw(), value.as_raw()))
@@ -319,6 +523,57 @@ impl Builder {
         }
     }
 
+    // NOTE: the guard also restores the current debug location. If the instruction before which the
+    // builder inserts is moved to another block or removed, the builder is positioned at the end of
+    // the saved block instead, but erasing it while the guard is alive is not supported.
+    pub fn save_insertion_point(&self) -> InsertionPointGuard<'_> {
+        InsertionPointGuard {
+            basic_block: self.get_insert_block(),
+            builder: self,
+            debug_location: self.get_current_debug_location(),
+            insert_before: self.insert_before.get(),
+        }
+    }
+
+    pub fn set_current_debug_location(&self, location: Option<&Metadata>) {
+        let location = location.map(Metadata::as_raw).unwrap_or_else(ptr::null_mut);
+        unsafe {
+            LLVMSetCurrentDebugLocation2(self.as_raw(), location);
+        }
+    }
+
+    pub fn set_debug_verify(&self, debug_verify: bool) {
+        self.debug_verify.set(debug_verify);
+    }
+
+    pub fn shl(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildShl(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn sign_extend(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildSExt(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn signed_int_to_floating_point(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildSIToFP(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn srem(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildSRem(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn store(&self, value: &Value, pointer: &Value) -> Value {
         debug_assert_eq!(value.get_type(), pointer.get_type().element_type(), "`value` type is different than the type pointed by `pointer`");
         unsafe {
@@ -340,12 +595,47 @@ impl Builder {
         }
     }
 
+    pub fn trunc(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildTrunc(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn udiv(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildUDiv(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn unsigned_int_to_floating_point(&self, value: &Value, dest_type: Type, name: &str) -> Value {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
             Value::from_raw(LLVMBuildUIToFP(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
         }
     }
+
+    pub fn urem(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildURem(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn xor(&self, left: &Value, right: &Value, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildXor(self.as_raw(), left.as_raw(), right.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn zero_extend(&self, value: &Value, dest_type: Type, name: &str) -> Value {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Value::from_raw(LLVMBuildZExt(self.as_raw(), value.as_raw(), dest_type.as_raw(), cstring.as_ptr()))
+        }
+    }
 }
 
 impl Drop for Builder {
@@ -355,3 +645,29 @@ impl Drop for Builder {
         }
     }
 }
+
+pub struct InsertionPointGuard<'a> {
+    basic_block: Option<BasicBlock>,
+    builder: &'a Builder,
+    debug_location: Option<Metadata>,
+    insert_before: LLVMValueRef,
+}
+
+impl<'a> Drop for InsertionPointGuard<'a> {
+    fn drop(&mut self) {
+        let insert_before =
+            if self.insert_before.is_null() {
+                None
+            }
+            else {
+                Some(unsafe { Instruction::from_raw(self.insert_before) })
+            };
+        match (insert_before, self.basic_block.as_ref()) {
+            (Some(instruction), Some(basic_block)) if instruction.parent().as_ref() == Some(basic_block) =>
+                self.builder.position_before(&instruction),
+            (_, Some(basic_block)) => self.builder.position_at_end(basic_block),
+            (_, None) => self.builder.clear_insertion_position(),
+        }
+        self.builder.set_current_debug_location(self.debug_location.as_ref());
+    }
+}
@@ -0,0 +1,132 @@
+use std::os::raw::c_uint;
+
+use ffi::{
+    LLVMGetFunctionCallConv,
+    LLVMGetInstructionCallConv,
+    LLVMSetFunctionCallConv,
+    LLVMSetInstructionCallConv,
+};
+use instruction::Instruction;
+use module::Function;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CallingConvention {
+    C,
+    Fast,
+    Cold,
+    GHC,
+    HiPE,
+    AnyReg,
+    PreserveMost,
+    PreserveAll,
+    Swift,
+    CxxFastTLS,
+    Tail,
+    SwiftTail,
+    X86Stdcall,
+    X86Fastcall,
+    X86ThisCall,
+    X86VectorCall,
+    X86RegCall,
+    X86Interrupt,
+    X86_64SysV,
+    Win64,
+    ArmAPCS,
+    ArmAAPCS,
+    ArmAAPCSVFP,
+    // NOTE: only for the numbers without a named variant: use CallingConvention::new() to get the
+    // calling convention of a number.
+    Other(u32),
+}
+
+impl CallingConvention {
+    pub fn new(calling_convention: u32) -> Self {
+        Self::from_raw(calling_convention)
+    }
+
+    fn as_raw(&self) -> c_uint {
+        match *self {
+            CallingConvention::C => 0,
+            CallingConvention::Fast => 8,
+            CallingConvention::Cold => 9,
+            CallingConvention::GHC => 10,
+            CallingConvention::HiPE => 11,
+            CallingConvention::AnyReg => 13,
+            CallingConvention::PreserveMost => 14,
+            CallingConvention::PreserveAll => 15,
+            CallingConvention::Swift => 16,
+            CallingConvention::CxxFastTLS => 17,
+            CallingConvention::Tail => 18,
+            CallingConvention::SwiftTail => 20,
+            CallingConvention::X86Stdcall => 64,
+            CallingConvention::X86Fastcall => 65,
+            CallingConvention::ArmAPCS => 66,
+            CallingConvention::ArmAAPCS => 67,
+            CallingConvention::ArmAAPCSVFP => 68,
+            CallingConvention::X86ThisCall => 70,
+            CallingConvention::X86_64SysV => 78,
+            CallingConvention::Win64 => 79,
+            CallingConvention::X86VectorCall => 80,
+            CallingConvention::X86Interrupt => 83,
+            CallingConvention::X86RegCall => 92,
+            CallingConvention::Other(calling_convention) => calling_convention,
+        }
+    }
+
+    fn from_raw(calling_convention: c_uint) -> Self {
+        match calling_convention {
+            0 => CallingConvention::C,
+            8 => CallingConvention::Fast,
+            9 => CallingConvention::Cold,
+            10 => CallingConvention::GHC,
+            11 => CallingConvention::HiPE,
+            13 => CallingConvention::AnyReg,
+            14 => CallingConvention::PreserveMost,
+            15 => CallingConvention::PreserveAll,
+            16 => CallingConvention::Swift,
+            17 => CallingConvention::CxxFastTLS,
+            18 => CallingConvention::Tail,
+            20 => CallingConvention::SwiftTail,
+            64 => CallingConvention::X86Stdcall,
+            65 => CallingConvention::X86Fastcall,
+            66 => CallingConvention::ArmAPCS,
+            67 => CallingConvention::ArmAAPCS,
+            68 => CallingConvention::ArmAAPCSVFP,
+            70 => CallingConvention::X86ThisCall,
+            78 => CallingConvention::X86_64SysV,
+            79 => CallingConvention::Win64,
+            80 => CallingConvention::X86VectorCall,
+            83 => CallingConvention::X86Interrupt,
+            92 => CallingConvention::X86RegCall,
+            _ => CallingConvention::Other(calling_convention),
+        }
+    }
+}
+
+impl Function {
+    pub fn call_conv(&self) -> CallingConvention {
+        unsafe {
+            CallingConvention::from_raw(LLVMGetFunctionCallConv(self.as_raw()))
+        }
+    }
+
+    pub fn set_call_conv(&self, calling_convention: CallingConvention) {
+        unsafe {
+            LLVMSetFunctionCallConv(self.as_raw(), calling_convention.as_raw());
+        }
+    }
+}
+
+impl Instruction {
+    pub fn call_conv(&self) -> CallingConvention {
+        unsafe {
+            CallingConvention::from_raw(LLVMGetInstructionCallConv(self.as_raw()))
+        }
+    }
+
+    pub fn set_call_conv(&self, calling_convention: CallingConvention) {
+        unsafe {
+            LLVMSetInstructionCallConv(self.as_raw(), calling_convention.as_raw());
+        }
+    }
+}
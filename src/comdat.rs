@@ -0,0 +1,65 @@
+use ffi::{
+    LLVMComdatRef,
+    LLVMComdatSelectionKind,
+    LLVMGetComdatSelectionKind,
+    LLVMSetComdatSelectionKind,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComdatSelectionKind {
+    Any,
+    ExactMatch,
+    Largest,
+    NoDeduplicate,
+    SameSize,
+}
+
+impl ComdatSelectionKind {
+    fn as_raw(&self) -> LLVMComdatSelectionKind {
+        match *self {
+            ComdatSelectionKind::Any => LLVMComdatSelectionKind::LLVMAnyComdatSelectionKind,
+            ComdatSelectionKind::ExactMatch => LLVMComdatSelectionKind::LLVMExactMatchComdatSelectionKind,
+            ComdatSelectionKind::Largest => LLVMComdatSelectionKind::LLVMLargestComdatSelectionKind,
+            ComdatSelectionKind::NoDeduplicate => LLVMComdatSelectionKind::LLVMNoDeduplicateComdatSelectionKind,
+            ComdatSelectionKind::SameSize => LLVMComdatSelectionKind::LLVMSameSizeComdatSelectionKind,
+        }
+    }
+
+    fn from_raw(kind: LLVMComdatSelectionKind) -> Self {
+        match kind {
+            LLVMComdatSelectionKind::LLVMAnyComdatSelectionKind => ComdatSelectionKind::Any,
+            LLVMComdatSelectionKind::LLVMExactMatchComdatSelectionKind => ComdatSelectionKind::ExactMatch,
+            LLVMComdatSelectionKind::LLVMLargestComdatSelectionKind => ComdatSelectionKind::Largest,
+            LLVMComdatSelectionKind::LLVMNoDeduplicateComdatSelectionKind => ComdatSelectionKind::NoDeduplicate,
+            LLVMComdatSelectionKind::LLVMSameSizeComdatSelectionKind => ComdatSelectionKind::SameSize,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comdat(LLVMComdatRef);
+
+impl Comdat {
+    /// # Safety
+    ///
+    /// `comdat` must be a valid comdat.
+    pub unsafe fn from_raw(comdat: LLVMComdatRef) -> Self {
+        Self(comdat)
+    }
+
+    pub fn as_raw(&self) -> LLVMComdatRef {
+        self.0
+    }
+
+    pub fn get_selection_kind(&self) -> ComdatSelectionKind {
+        unsafe {
+            ComdatSelectionKind::from_raw(LLVMGetComdatSelectionKind(self.as_raw()))
+        }
+    }
+
+    pub fn set_selection_kind(&self, kind: ComdatSelectionKind) {
+        unsafe {
+            LLVMSetComdatSelectionKind(self.as_raw(), kind.as_raw());
+        }
+    }
+}
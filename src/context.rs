@@ -1,16 +1,62 @@
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::ptr;
+
 use Module;
 use ffi::{
     LLVMContextCreate,
     LLVMContextDispose,
     LLVMContextRef,
+    LLVMContextSetDiagnosticHandler,
+    LLVMContextSetDiscardValueNames,
+    LLVMContextShouldDiscardValueNames,
+    LLVMDiagnosticInfoRef,
+    LLVMDiagnosticSeverity,
+    LLVMDisposeMessage,
+    LLVMGetDiagInfoDescription,
+    LLVMGetDiagInfoSeverity,
 };
 
-pub struct Context(LLVMContextRef);
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Remark,
+    Note,
+}
+
+impl DiagnosticSeverity {
+    fn from_raw(severity: LLVMDiagnosticSeverity) -> Self {
+        match severity {
+            LLVMDiagnosticSeverity::LLVMDSError => DiagnosticSeverity::Error,
+            LLVMDiagnosticSeverity::LLVMDSWarning => DiagnosticSeverity::Warning,
+            LLVMDiagnosticSeverity::LLVMDSRemark => DiagnosticSeverity::Remark,
+            LLVMDiagnosticSeverity::LLVMDSNote => DiagnosticSeverity::Note,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub description: String,
+    pub severity: DiagnosticSeverity,
+}
+
+type DiagnosticHandler = Box<dyn FnMut(Diagnostic) + Send>;
+
+pub struct Context {
+    context: LLVMContextRef,
+    // NOTE: boxed twice so that LLVM gets a thin pointer which stays valid when the context moves.
+    diagnostic_handler: Option<Box<DiagnosticHandler>>,
+}
 
 impl Context {
     pub fn new() -> Self {
         unsafe {
-            Context(LLVMContextCreate())
+            Context {
+                context: LLVMContextCreate(),
+                diagnostic_handler: None,
+            }
         }
     }
 
@@ -19,7 +65,36 @@ impl Context {
     }
 
     pub fn as_raw(&self) -> LLVMContextRef {
-        self.0
+        self.context
+    }
+
+    // NOTE: without a handler, LLVM prints the diagnostics to stderr and exits on errors.
+    pub fn clear_diagnostic_handler(&mut self) {
+        unsafe {
+            LLVMContextSetDiagnosticHandler(self.as_raw(), None, ptr::null_mut());
+        }
+        self.diagnostic_handler = None;
+    }
+
+    pub fn set_diagnostic_handler<F: FnMut(Diagnostic) + Send + 'static>(&mut self, handler: F) {
+        let mut handler: Box<DiagnosticHandler> = Box::new(Box::new(handler));
+        unsafe {
+            let handler_ptr = &mut *handler as *mut DiagnosticHandler as *mut c_void;
+            LLVMContextSetDiagnosticHandler(self.as_raw(), Some(diagnostic_handler), handler_ptr);
+        }
+        self.diagnostic_handler = Some(handler);
+    }
+
+    pub fn set_discard_value_names(&self, discard: bool) {
+        unsafe {
+            LLVMContextSetDiscardValueNames(self.as_raw(), discard as i32);
+        }
+    }
+
+    pub fn should_discard_value_names(&self) -> bool {
+        unsafe {
+            LLVMContextShouldDiscardValueNames(self.as_raw()) != 0
+        }
     }
 }
 
@@ -30,3 +105,17 @@ impl Drop for Context {
         }
     }
 }
+
+extern "C" fn diagnostic_handler(info: LLVMDiagnosticInfoRef, handler: *mut c_void) {
+    unsafe {
+        let message = LLVMGetDiagInfoDescription(info);
+        let description = CStr::from_ptr(message).to_string_lossy().into_owned();
+        LLVMDisposeMessage(message);
+        let diagnostic = Diagnostic {
+            description,
+            severity: DiagnosticSeverity::from_raw(LLVMGetDiagInfoSeverity(info)),
+        };
+        let handler = &mut *(handler as *mut DiagnosticHandler);
+        handler(diagnostic);
+    }
+}
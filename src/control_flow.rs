@@ -0,0 +1,144 @@
+use std::ffi::CString;
+
+use {BasicBlock, Builder, Value};
+use ffi::{
+    LLVMAppendBasicBlockInContext,
+    LLVMGetTypeContext,
+    LLVMGetUndef,
+    LLVMTypeOf,
+};
+use module::Function;
+
+pub struct Loop {
+    break_block: BasicBlock,
+    continue_block: BasicBlock,
+}
+
+impl Loop {
+    pub fn break_block(&self) -> &BasicBlock {
+        &self.break_block
+    }
+
+    pub fn build_break(&self, builder: &Builder) {
+        builder.br(&self.break_block);
+        start_unreachable_block(builder);
+    }
+
+    pub fn build_break_if(&self, builder: &Builder, condition: &Value) {
+        let function = current_function(builder);
+        let next_block = append_basic_block(&function, "loopbody");
+        builder.cond_br(condition, &self.break_block, &next_block);
+        builder.position_at_end(&next_block);
+    }
+
+    pub fn build_continue(&self, builder: &Builder) {
+        builder.br(&self.continue_block);
+        start_unreachable_block(builder);
+    }
+
+    pub fn continue_block(&self) -> &BasicBlock {
+        &self.continue_block
+    }
+}
+
+impl Builder {
+    // NOTE: a branch that ends with a terminator (e.g. a ret) does not flow into the merge block and
+    // its value is not added to the phi.
+    pub fn build_if<T, E>(&self, condition: &Value, then: T, else_: E, name: &str) -> Value
+    where T: FnOnce(&Builder) -> Value,
+          E: FnOnce(&Builder) -> Value,
+    {
+        let function = current_function(self);
+        let then_block = append_basic_block(&function, "then");
+        let else_block = append_basic_block(&function, "else");
+        let merge_block = append_basic_block(&function, "ifcont");
+        self.cond_br(condition, &then_block, &else_block);
+
+        self.position_at_end(&then_block);
+        let then_value = then(self);
+        let then_end = self.branch_to(&merge_block);
+
+        self.position_at_end(&else_block);
+        let else_value = else_(self);
+        let else_end = self.branch_to(&merge_block);
+        debug_assert_eq!(then_value.get_type(), else_value.get_type(), "both branches of an if should have the same type");
+
+        self.position_at_end(&merge_block);
+        let mut incoming = vec![];
+        if let Some(ref block) = then_end {
+            incoming.push((&then_value, block));
+        }
+        if let Some(ref block) = else_end {
+            incoming.push((&else_value, block));
+        }
+        if incoming.is_empty() {
+            return unsafe { Value::from_raw(LLVMGetUndef(then_value.get_type().as_raw())) };
+        }
+        self.phi_with_incoming(then_value.get_type(), &incoming, name)
+    }
+
+    pub fn build_if_then<T>(&self, condition: &Value, then: T)
+    where T: FnOnce(&Builder),
+    {
+        let function = current_function(self);
+        let then_block = append_basic_block(&function, "then");
+        let merge_block = append_basic_block(&function, "ifcont");
+        self.cond_br(condition, &then_block, &merge_block);
+
+        self.position_at_end(&then_block);
+        then(self);
+        self.branch_to(&merge_block);
+
+        self.position_at_end(&merge_block);
+    }
+
+    // NOTE: the body is executed until a break. The builder is positioned after the loop when this
+    // method returns.
+    pub fn build_loop<B>(&self, body: B)
+    where B: FnOnce(&Builder, &Loop),
+    {
+        let function = current_function(self);
+        let loop_ = Loop {
+            continue_block: append_basic_block(&function, "loop"),
+            break_block: append_basic_block(&function, "afterloop"),
+        };
+        self.br(&loop_.continue_block);
+
+        self.position_at_end(&loop_.continue_block);
+        body(self, &loop_);
+        self.branch_to(&loop_.continue_block);
+
+        self.position_at_end(&loop_.break_block);
+    }
+
+    // Branch to the destination unless the current block is already terminated and return the
+    // block that branches.
+    fn branch_to(&self, destination: &BasicBlock) -> Option<BasicBlock> {
+        let block = self.get_insert_block().expect("builder insert block");
+        if block.terminator().is_some() {
+            return None;
+        }
+        self.br(destination);
+        Some(block)
+    }
+}
+
+fn append_basic_block(function: &Function, name: &str) -> BasicBlock {
+    let cstring = CString::new(name).expect("cstring");
+    unsafe {
+        let context = LLVMGetTypeContext(LLVMTypeOf(function.as_raw()));
+        BasicBlock::from_raw(LLVMAppendBasicBlockInContext(context, function.as_raw(), cstring.as_ptr()))
+    }
+}
+
+fn current_function(builder: &Builder) -> Function {
+    builder.get_insert_block().expect("position the builder before building control flow").get_parent()
+}
+
+// Code emitted after a break or a continue is unreachable, so put it in its own block to keep the IR
+// valid.
+fn start_unreachable_block(builder: &Builder) {
+    let function = current_function(builder);
+    let block = append_basic_block(&function, "unreachable");
+    builder.position_at_end(&block);
+}
@@ -0,0 +1,73 @@
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::result;
+
+use self::Error::*;
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Link(String),
+    ParseBitcode(String),
+    ParseIR(ParseDiagnostic),
+    Print(String),
+    Read(String),
+    RunPasses(String),
+    WriteBitcode(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match *self {
+            Link(ref error) => write!(formatter, "cannot link modules: {}", error),
+            ParseBitcode(ref error) => write!(formatter, "cannot parse bitcode: {}", error),
+            ParseIR(ref diagnostic) => write!(formatter, "cannot parse IR: {}", diagnostic),
+            Print(ref error) => write!(formatter, "cannot print module: {}", error),
+            Read(ref error) => write!(formatter, "cannot read file: {}", error),
+            RunPasses(ref error) => write!(formatter, "cannot run passes: {}", error),
+            WriteBitcode(ref filename) => write!(formatter, "cannot write bitcode to {}", filename),
+        }
+    }
+}
+
+impl error::Error for Error {
+}
+
+#[derive(Debug)]
+pub struct ParseDiagnostic {
+    pub column: usize,
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseDiagnostic {
+    // The message has the form "<buffer name>:<line>:<column>: error: <message>", followed by the
+    // offending source line.
+    pub(crate) fn from_message(message: &str) -> Self {
+        let first_line = message.lines().next().unwrap_or("");
+        if let Some(index) = first_line.find(": error: ") {
+            let mut location = first_line[..index].rsplitn(3, ':');
+            let column = location.next().and_then(|column| column.parse().ok());
+            let line = location.next().and_then(|line| line.parse().ok());
+            if let (Some(line), Some(column)) = (line, column) {
+                return Self {
+                    column,
+                    line,
+                    message: first_line[index + ": error: ".len()..].to_string(),
+                };
+            }
+        }
+        Self {
+            column: 0,
+            line: 0,
+            message: first_line.to_string(),
+        }
+    }
+}
+
+impl Display for ParseDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
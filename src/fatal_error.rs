@@ -0,0 +1,58 @@
+/*
+ * LLVM reports unrecoverable errors by calling the fatal error handler and then exiting or aborting
+ * the process: the handler is only a chance to report the error (e.g. to log it or to notify a parent
+ * process) since it cannot return to the caller. Unwinding out of the handler is not supported
+ * either, so code which must survive these errors has to run in a separate process.
+ *
+ * The rlvm calls which can trigger a fatal error are:
+ *  - ExecutionEngine::get_function_address(), which generates the code of the module: an
+ *    instruction or intrinsic that the target does not support ("Cannot select"), or a call to an
+ *    external function that cannot be resolved.
+ *  - TargetMachine::emit_to_file(), for the same codegen errors.
+ *  - Module::verify() and Function::verify() with VerifierFailureAction::AbortProcess, when the IR is
+ *    invalid.
+ */
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+
+use ffi::{
+    LLVMInstallFatalErrorHandler,
+    LLVMResetFatalErrorHandler,
+};
+
+static FATAL_ERROR_HANDLER: Mutex<Option<fn(&str)>> = Mutex::new(None);
+
+// NOTE: installing a handler replaces the previous one.
+pub fn install_fatal_error_handler(handler: fn(&str)) {
+    let mut current_handler = FATAL_ERROR_HANDLER.lock().expect("fatal error handler lock");
+    // LLVM asserts that no handler is registered when installing one, so the C handler is only
+    // installed once and swapping handlers only changes the Rust function it calls.
+    if current_handler.replace(handler).is_none() {
+        unsafe {
+            LLVMInstallFatalErrorHandler(Some(fatal_error_handler));
+        }
+    }
+}
+
+pub fn reset_fatal_error_handler() {
+    let mut current_handler = FATAL_ERROR_HANDLER.lock().expect("fatal error handler lock");
+    if current_handler.take().is_some() {
+        unsafe {
+            LLVMResetFatalErrorHandler();
+        }
+    }
+}
+
+extern "C" fn fatal_error_handler(reason: *const c_char) {
+    let reason = unsafe { CStr::from_ptr(reason) }.to_string_lossy();
+    // NOTE: do not panic on a poisoned lock: panicking here would abort without calling the handler.
+    let handler = match FATAL_ERROR_HANDLER.lock() {
+        Ok(handler) => *handler,
+        Err(error) => *error.into_inner(),
+    };
+    if let Some(handler) = handler {
+        handler(&reason);
+    }
+}
@@ -1,5 +1,6 @@
 use std::os::raw::{
     c_char,
+    c_int,
     c_uint,
     c_ulonglong,
     c_void,
@@ -18,6 +19,17 @@ pub type LLVMContextRef = *mut c_void;
 pub type LLVMTargetDataRef = *mut c_void;
 pub type LLVMTargetRef = *mut c_void;
 pub type LLVMTargetMachineRef = *mut c_void;
+pub type LLVMMemoryBufferRef = *mut c_void;
+pub type LLVMMetadataRef = *mut c_void;
+pub type LLVMAttributeRef = *mut c_void;
+pub type LLVMAttributeIndex = c_uint;
+pub type LLVMComdatRef = *mut c_void;
+pub type LLVMUseRef = *mut c_void;
+pub type LLVMDiagnosticInfoRef = *mut c_void;
+pub type LLVMDiagnosticHandler = Option<extern "C" fn(LLVMDiagnosticInfoRef, *mut c_void)>;
+pub type LLVMErrorRef = *mut c_void;
+pub type LLVMPassBuilderOptionsRef = *mut c_void;
+pub type LLVMFatalErrorHandler = Option<extern "C" fn(*const c_char)>;
 
 #[repr(C)]
 pub enum LLVMCodeGenFileType {
@@ -97,6 +109,180 @@ pub enum LLVMCodeModel {
     LLVMCodeModelLarge
 }
 
+#[repr(C)]
+pub enum LLVMOpcode {
+    LLVMRet = 1,
+    LLVMBr = 2,
+    LLVMSwitch = 3,
+    LLVMIndirectBr = 4,
+    LLVMInvoke = 5,
+    LLVMUnreachable = 7,
+    LLVMCallBr = 67,
+    LLVMFNeg = 66,
+    LLVMAdd = 8,
+    LLVMFAdd = 9,
+    LLVMSub = 10,
+    LLVMFSub = 11,
+    LLVMMul = 12,
+    LLVMFMul = 13,
+    LLVMUDiv = 14,
+    LLVMSDiv = 15,
+    LLVMFDiv = 16,
+    LLVMURem = 17,
+    LLVMSRem = 18,
+    LLVMFRem = 19,
+    LLVMShl = 20,
+    LLVMLShr = 21,
+    LLVMAShr = 22,
+    LLVMAnd = 23,
+    LLVMOr = 24,
+    LLVMXor = 25,
+    LLVMAlloca = 26,
+    LLVMLoad = 27,
+    LLVMStore = 28,
+    LLVMGetElementPtr = 29,
+    LLVMTrunc = 30,
+    LLVMZExt = 31,
+    LLVMSExt = 32,
+    LLVMFPToUI = 33,
+    LLVMFPToSI = 34,
+    LLVMUIToFP = 35,
+    LLVMSIToFP = 36,
+    LLVMFPTrunc = 37,
+    LLVMFPExt = 38,
+    LLVMPtrToInt = 39,
+    LLVMIntToPtr = 40,
+    LLVMBitCast = 41,
+    LLVMAddrSpaceCast = 60,
+    LLVMICmp = 42,
+    LLVMFCmp = 43,
+    LLVMPHI = 44,
+    LLVMCall = 45,
+    LLVMSelect = 46,
+    LLVMUserOp1 = 47,
+    LLVMUserOp2 = 48,
+    LLVMVAArg = 49,
+    LLVMExtractElement = 50,
+    LLVMInsertElement = 51,
+    LLVMShuffleVector = 52,
+    LLVMExtractValue = 53,
+    LLVMInsertValue = 54,
+    LLVMFreeze = 68,
+    LLVMFence = 55,
+    LLVMAtomicCmpXchg = 56,
+    LLVMAtomicRMW = 57,
+    LLVMResume = 58,
+    LLVMLandingPad = 59,
+    LLVMCleanupRet = 61,
+    LLVMCatchRet = 62,
+    LLVMCatchPad = 63,
+    LLVMCleanupPad = 64,
+    LLVMCatchSwitch = 65,
+}
+
+#[repr(C)]
+pub enum LLVMModuleFlagBehavior {
+    LLVMModuleFlagBehaviorError,
+    LLVMModuleFlagBehaviorWarning,
+    LLVMModuleFlagBehaviorRequire,
+    LLVMModuleFlagBehaviorOverride,
+    LLVMModuleFlagBehaviorAppend,
+    LLVMModuleFlagBehaviorAppendUnique,
+}
+
+#[repr(C)]
+pub enum LLVMLinkage {
+    LLVMExternalLinkage,
+    LLVMAvailableExternallyLinkage,
+    LLVMLinkOnceAnyLinkage,
+    LLVMLinkOnceODRLinkage,
+    LLVMLinkOnceODRAutoHideLinkage,
+    LLVMWeakAnyLinkage,
+    LLVMWeakODRLinkage,
+    LLVMAppendingLinkage,
+    LLVMInternalLinkage,
+    LLVMPrivateLinkage,
+    LLVMDLLImportLinkage,
+    LLVMDLLExportLinkage,
+    LLVMExternalWeakLinkage,
+    LLVMGhostLinkage,
+    LLVMCommonLinkage,
+    LLVMLinkerPrivateLinkage,
+    LLVMLinkerPrivateWeakLinkage,
+}
+
+#[repr(C)]
+pub enum LLVMVisibility {
+    LLVMDefaultVisibility,
+    LLVMHiddenVisibility,
+    LLVMProtectedVisibility,
+}
+
+#[repr(C)]
+pub enum LLVMDLLStorageClass {
+    LLVMDefaultStorageClass,
+    LLVMDLLImportStorageClass,
+    LLVMDLLExportStorageClass,
+}
+
+#[repr(C)]
+pub enum LLVMUnnamedAddr {
+    LLVMNoUnnamedAddr,
+    LLVMLocalUnnamedAddr,
+    LLVMGlobalUnnamedAddr,
+}
+
+#[repr(C)]
+pub enum LLVMThreadLocalMode {
+    LLVMNotThreadLocal,
+    LLVMGeneralDynamicTLSModel,
+    LLVMLocalDynamicTLSModel,
+    LLVMInitialExecTLSModel,
+    LLVMLocalExecTLSModel,
+}
+
+#[repr(C)]
+pub enum LLVMComdatSelectionKind {
+    LLVMAnyComdatSelectionKind,
+    LLVMExactMatchComdatSelectionKind,
+    LLVMLargestComdatSelectionKind,
+    LLVMNoDeduplicateComdatSelectionKind,
+    LLVMSameSizeComdatSelectionKind,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum LLVMTypeKind {
+    LLVMVoidTypeKind,
+    LLVMHalfTypeKind,
+    LLVMFloatTypeKind,
+    LLVMDoubleTypeKind,
+    LLVMX86_FP80TypeKind,
+    LLVMFP128TypeKind,
+    LLVMPPC_FP128TypeKind,
+    LLVMLabelTypeKind,
+    LLVMIntegerTypeKind,
+    LLVMFunctionTypeKind,
+    LLVMStructTypeKind,
+    LLVMArrayTypeKind,
+    LLVMPointerTypeKind,
+    LLVMVectorTypeKind,
+    LLVMMetadataTypeKind,
+    LLVMX86_MMXTypeKind,
+    LLVMTokenTypeKind,
+    LLVMScalableVectorTypeKind,
+    LLVMBFloatTypeKind,
+    LLVMX86_AMXTypeKind,
+}
+
+#[repr(C)]
+pub enum LLVMDiagnosticSeverity {
+    LLVMDSError,
+    LLVMDSWarning,
+    LLVMDSRemark,
+    LLVMDSNote,
+}
+
 #[link(name="LLVM-15")]
 extern "C" {
     pub fn LLVMModuleCreateWithName(ModuleID: *const c_char) -> LLVMModuleRef;
@@ -226,4 +412,228 @@ extern "C" {
     pub fn LLVMGetElementType(Ty: LLVMTypeRef) -> LLVMTypeRef;
     pub fn LLVMGetNumContainedTypes(Tp: LLVMTypeRef) -> c_uint;
     pub fn LLVMConstArray(ElementTy: LLVMTypeRef, ConstantVals: *mut LLVMValueRef, Length: c_uint) -> LLVMValueRef;
+    pub fn LLVMIsAInstruction(Val: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetInstructionOpcode(Inst: LLVMValueRef) -> LLVMOpcode;
+    pub fn LLVMGetInstructionParent(Inst: LLVMValueRef) -> LLVMBasicBlockRef;
+    pub fn LLVMGetNextInstruction(Inst: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetPreviousInstruction(Inst: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMInstructionRemoveFromParent(Inst: LLVMValueRef);
+    pub fn LLVMInstructionEraseFromParent(Inst: LLVMValueRef);
+    // Ownership: the clone has no parent and no name.
+    pub fn LLVMInstructionClone(Inst: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMIsATerminatorInst(Inst: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetNumSuccessors(Term: LLVMValueRef) -> c_uint;
+    pub fn LLVMGetSuccessor(Term: LLVMValueRef, i: c_uint) -> LLVMBasicBlockRef;
+    pub fn LLVMSetSuccessor(Term: LLVMValueRef, i: c_uint, block: LLVMBasicBlockRef);
+    pub fn LLVMPositionBuilderBefore(Builder: LLVMBuilderRef, Instr: LLVMValueRef);
+    pub fn LLVMInsertIntoBuilder(Builder: LLVMBuilderRef, Instr: LLVMValueRef);
+    pub fn LLVMGetTypeContext(Ty: LLVMTypeRef) -> LLVMContextRef;
+    pub fn LLVMGetFirstFunction(M: LLVMModuleRef) -> LLVMValueRef;
+    pub fn LLVMGetNextFunction(Fn: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetFirstGlobal(M: LLVMModuleRef) -> LLVMValueRef;
+    pub fn LLVMGetNextGlobal(GlobalVar: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetNamedGlobal(M: LLVMModuleRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMGetFirstGlobalAlias(M: LLVMModuleRef) -> LLVMValueRef;
+    pub fn LLVMGetNextGlobalAlias(GA: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetFirstGlobalIFunc(M: LLVMModuleRef) -> LLVMValueRef;
+    pub fn LLVMGetNextGlobalIFunc(IFunc: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetModuleContext(M: LLVMModuleRef) -> LLVMContextRef;
+    pub fn LLVMGetTypeByName2(C: LLVMContextRef, Name: *const c_char) -> LLVMTypeRef;
+    // Ownership: dispose the message.
+    pub fn LLVMPrintModuleToString(M: LLVMModuleRef) -> *mut c_char;
+    pub fn LLVMPrintModuleToFile(M: LLVMModuleRef, Filename: *const c_char, ErrorMessage: *mut *mut c_char) -> LLVMBool;
+    pub fn LLVMWriteBitcodeToFile(M: LLVMModuleRef, Path: *const c_char) -> c_int;
+    pub fn LLVMWriteBitcodeToMemoryBuffer(M: LLVMModuleRef) -> LLVMMemoryBufferRef;
+    pub fn LLVMParseBitcodeInContext(ContextRef: LLVMContextRef, MemBuf: LLVMMemoryBufferRef, OutModule: *mut LLVMModuleRef, OutMessage: *mut *mut c_char) -> LLVMBool;
+    // Ownership: takes ownership of the memory buffer on success.
+    pub fn LLVMGetBitcodeModuleInContext(ContextRef: LLVMContextRef, MemBuf: LLVMMemoryBufferRef, OutM: *mut LLVMModuleRef, OutMessage: *mut *mut c_char) -> LLVMBool;
+    pub fn LLVMCreateMemoryBufferWithContentsOfFile(Path: *const c_char, OutMemBuf: *mut LLVMMemoryBufferRef, OutMessage: *mut *mut c_char) -> LLVMBool;
+    pub fn LLVMCreateMemoryBufferWithMemoryRangeCopy(InputData: *const c_char, InputDataLength: usize, BufferName: *const c_char) -> LLVMMemoryBufferRef;
+    pub fn LLVMGetBufferStart(MemBuf: LLVMMemoryBufferRef) -> *const c_char;
+    pub fn LLVMGetBufferSize(MemBuf: LLVMMemoryBufferRef) -> usize;
+    pub fn LLVMDisposeMemoryBuffer(MemBuf: LLVMMemoryBufferRef);
+    // Ownership: takes ownership of the memory buffer.
+    pub fn LLVMParseIRInContext(ContextRef: LLVMContextRef, MemBuf: LLVMMemoryBufferRef, OutM: *mut LLVMModuleRef, OutMessage: *mut *mut c_char) -> LLVMBool;
+    pub fn LLVMCloneModule(M: LLVMModuleRef) -> LLVMModuleRef;
+    // Ownership: destroys the source module.
+    pub fn LLVMLinkModules2(Dest: LLVMModuleRef, Src: LLVMModuleRef) -> LLVMBool;
+    pub fn LLVMGetModuleIdentifier(M: LLVMModuleRef, Len: *mut usize) -> *const c_char;
+    pub fn LLVMSetModuleIdentifier(M: LLVMModuleRef, Ident: *const c_char, Len: usize);
+    pub fn LLVMGetSourceFileName(M: LLVMModuleRef, Len: *mut usize) -> *const c_char;
+    pub fn LLVMSetSourceFileName(M: LLVMModuleRef, Name: *const c_char, Len: usize);
+    pub fn LLVMGetModuleFlag(M: LLVMModuleRef, Key: *const c_char, KeyLen: usize) -> LLVMMetadataRef;
+    pub fn LLVMAddModuleFlag(M: LLVMModuleRef, Behavior: LLVMModuleFlagBehavior, Key: *const c_char, KeyLen: usize, Val: LLVMMetadataRef);
+    pub fn LLVMGetModuleInlineAsm(M: LLVMModuleRef, Len: *mut usize) -> *const c_char;
+    pub fn LLVMSetModuleInlineAsm2(M: LLVMModuleRef, Asm: *const c_char, Len: usize);
+    pub fn LLVMAppendModuleInlineAsm(M: LLVMModuleRef, Asm: *const c_char, Len: usize);
+    pub fn LLVMGetNamedMetadataNumOperands(M: LLVMModuleRef, Name: *const c_char) -> c_uint;
+    pub fn LLVMGetNamedMetadataOperands(M: LLVMModuleRef, Name: *const c_char, Dest: *mut LLVMValueRef);
+    pub fn LLVMAddNamedMetadataOperand(M: LLVMModuleRef, Name: *const c_char, Val: LLVMValueRef);
+    pub fn LLVMMDStringInContext2(C: LLVMContextRef, Str: *const c_char, SLen: usize) -> LLVMMetadataRef;
+    pub fn LLVMMDNodeInContext2(C: LLVMContextRef, MDs: *mut LLVMMetadataRef, Count: usize) -> LLVMMetadataRef;
+    pub fn LLVMMetadataAsValue(C: LLVMContextRef, MD: LLVMMetadataRef) -> LLVMValueRef;
+    pub fn LLVMValueAsMetadata(Val: LLVMValueRef) -> LLVMMetadataRef;
+    // Ownership: dispose the message.
+    pub fn LLVMCreateMessage(Message: *const c_char) -> *mut c_char;
+    // Ownership: dispose the message.
+    pub fn LLVMNormalizeTargetTriple(triple: *const c_char) -> *mut c_char;
+    pub fn LLVMGetTarget(M: LLVMModuleRef) -> *const c_char;
+    pub fn LLVMGetDataLayoutStr(M: LLVMModuleRef) -> *const c_char;
+    // Ownership: dispose the message.
+    pub fn LLVMCopyStringRepOfTargetData(TD: LLVMTargetDataRef) -> *mut c_char;
+    pub fn LLVMGetLinkage(Global: LLVMValueRef) -> LLVMLinkage;
+    pub fn LLVMSetLinkage(Global: LLVMValueRef, Linkage: LLVMLinkage);
+    pub fn LLVMGetVisibility(Global: LLVMValueRef) -> LLVMVisibility;
+    pub fn LLVMSetVisibility(Global: LLVMValueRef, Viz: LLVMVisibility);
+    pub fn LLVMGetDLLStorageClass(Global: LLVMValueRef) -> LLVMDLLStorageClass;
+    pub fn LLVMSetDLLStorageClass(Global: LLVMValueRef, Class: LLVMDLLStorageClass);
+    pub fn LLVMGetUnnamedAddress(Global: LLVMValueRef) -> LLVMUnnamedAddr;
+    pub fn LLVMSetUnnamedAddress(Global: LLVMValueRef, UnnamedAddr: LLVMUnnamedAddr);
+    pub fn LLVMGetEnumAttributeKindForName(Name: *const c_char, SLen: usize) -> c_uint;
+    pub fn LLVMCreateEnumAttribute(C: LLVMContextRef, KindID: c_uint, Val: u64) -> LLVMAttributeRef;
+    pub fn LLVMGetEnumAttributeKind(A: LLVMAttributeRef) -> c_uint;
+    pub fn LLVMGetEnumAttributeValue(A: LLVMAttributeRef) -> u64;
+    pub fn LLVMCreateTypeAttribute(C: LLVMContextRef, KindID: c_uint, type_ref: LLVMTypeRef) -> LLVMAttributeRef;
+    pub fn LLVMGetTypeAttributeValue(A: LLVMAttributeRef) -> LLVMTypeRef;
+    pub fn LLVMCreateStringAttribute(C: LLVMContextRef, K: *const c_char, KLength: c_uint, V: *const c_char, VLength: c_uint) -> LLVMAttributeRef;
+    pub fn LLVMGetStringAttributeKind(A: LLVMAttributeRef, Length: *mut c_uint) -> *const c_char;
+    pub fn LLVMGetStringAttributeValue(A: LLVMAttributeRef, Length: *mut c_uint) -> *const c_char;
+    pub fn LLVMIsEnumAttribute(A: LLVMAttributeRef) -> LLVMBool;
+    pub fn LLVMIsStringAttribute(A: LLVMAttributeRef) -> LLVMBool;
+    pub fn LLVMIsTypeAttribute(A: LLVMAttributeRef) -> LLVMBool;
+    pub fn LLVMAddAttributeAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, A: LLVMAttributeRef);
+    pub fn LLVMGetAttributeCountAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex) -> c_uint;
+    pub fn LLVMGetAttributesAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, Attrs: *mut LLVMAttributeRef);
+    pub fn LLVMGetEnumAttributeAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, KindID: c_uint) -> LLVMAttributeRef;
+    pub fn LLVMGetStringAttributeAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, K: *const c_char, KLen: c_uint) -> LLVMAttributeRef;
+    pub fn LLVMRemoveEnumAttributeAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, KindID: c_uint);
+    pub fn LLVMRemoveStringAttributeAtIndex(F: LLVMValueRef, Idx: LLVMAttributeIndex, K: *const c_char, KLen: c_uint);
+    pub fn LLVMAddCallSiteAttribute(C: LLVMValueRef, Idx: LLVMAttributeIndex, A: LLVMAttributeRef);
+    pub fn LLVMGetCallSiteAttributeCount(C: LLVMValueRef, Idx: LLVMAttributeIndex) -> c_uint;
+    pub fn LLVMGetCallSiteAttributes(C: LLVMValueRef, Idx: LLVMAttributeIndex, Attrs: *mut LLVMAttributeRef);
+    pub fn LLVMGetCallSiteEnumAttribute(C: LLVMValueRef, Idx: LLVMAttributeIndex, KindID: c_uint) -> LLVMAttributeRef;
+    pub fn LLVMGetCallSiteStringAttribute(C: LLVMValueRef, Idx: LLVMAttributeIndex, K: *const c_char, KLen: c_uint) -> LLVMAttributeRef;
+    pub fn LLVMRemoveCallSiteEnumAttribute(C: LLVMValueRef, Idx: LLVMAttributeIndex, KindID: c_uint);
+    pub fn LLVMRemoveCallSiteStringAttribute(C: LLVMValueRef, Idx: LLVMAttributeIndex, K: *const c_char, KLen: c_uint);
+    pub fn LLVMGetFunctionCallConv(Fn: LLVMValueRef) -> c_uint;
+    pub fn LLVMSetFunctionCallConv(Fn: LLVMValueRef, CC: c_uint);
+    pub fn LLVMGetInstructionCallConv(Instr: LLVMValueRef) -> c_uint;
+    pub fn LLVMSetInstructionCallConv(Instr: LLVMValueRef, CC: c_uint);
+    pub fn LLVMGetFirstBasicBlock(Fn: LLVMValueRef) -> LLVMBasicBlockRef;
+    pub fn LLVMGetLastBasicBlock(Fn: LLVMValueRef) -> LLVMBasicBlockRef;
+    pub fn LLVMGetNextBasicBlock(BB: LLVMBasicBlockRef) -> LLVMBasicBlockRef;
+    pub fn LLVMGetFirstParam(Fn: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetNextParam(Arg: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetValueName2(Val: LLVMValueRef, Length: *mut usize) -> *const c_char;
+    pub fn LLVMGlobalGetValueType(Global: LLVMValueRef) -> LLVMTypeRef;
+    pub fn LLVMGetReturnType(FunctionTy: LLVMTypeRef) -> LLVMTypeRef;
+    pub fn LLVMIsDeclaration(Global: LLVMValueRef) -> LLVMBool;
+    pub fn LLVMGetSection(Global: LLVMValueRef) -> *const c_char;
+    pub fn LLVMSetSection(Global: LLVMValueRef, Section: *const c_char);
+    pub fn LLVMGetAlignment(V: LLVMValueRef) -> c_uint;
+    pub fn LLVMSetAlignment(V: LLVMValueRef, Bytes: c_uint);
+    pub fn LLVMGetGC(Fn: LLVMValueRef) -> *const c_char;
+    pub fn LLVMSetGC(Fn: LLVMValueRef, Name: *const c_char);
+    pub fn LLVMDeleteGlobal(GlobalVar: LLVMValueRef);
+    pub fn LLVMGetInitializer(GlobalVar: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMIsThreadLocal(GlobalVar: LLVMValueRef) -> LLVMBool;
+    pub fn LLVMSetThreadLocal(GlobalVar: LLVMValueRef, IsThreadLocal: LLVMBool);
+    pub fn LLVMIsGlobalConstant(GlobalVar: LLVMValueRef) -> LLVMBool;
+    pub fn LLVMSetGlobalConstant(GlobalVar: LLVMValueRef, IsConstant: LLVMBool);
+    pub fn LLVMGetThreadLocalMode(GlobalVar: LLVMValueRef) -> LLVMThreadLocalMode;
+    pub fn LLVMSetThreadLocalMode(GlobalVar: LLVMValueRef, Mode: LLVMThreadLocalMode);
+    pub fn LLVMIsExternallyInitialized(GlobalVar: LLVMValueRef) -> LLVMBool;
+    pub fn LLVMSetExternallyInitialized(GlobalVar: LLVMValueRef, IsExtInit: LLVMBool);
+    pub fn LLVMAddAlias2(M: LLVMModuleRef, ValueTy: LLVMTypeRef, AddrSpace: c_uint, Aliasee: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMAliasGetAliasee(Alias: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMAliasSetAliasee(Alias: LLVMValueRef, Aliasee: LLVMValueRef);
+    pub fn LLVMAddGlobalIFunc(M: LLVMModuleRef, Name: *const c_char, NameLen: usize, Ty: LLVMTypeRef, AddrSpace: c_uint, Resolver: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetGlobalIFuncResolver(IFunc: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMSetGlobalIFuncResolver(IFunc: LLVMValueRef, Resolver: LLVMValueRef);
+    pub fn LLVMGetOrInsertComdat(M: LLVMModuleRef, Name: *const c_char) -> LLVMComdatRef;
+    pub fn LLVMGetComdat(V: LLVMValueRef) -> LLVMComdatRef;
+    pub fn LLVMSetComdat(V: LLVMValueRef, C: LLVMComdatRef);
+    pub fn LLVMGetComdatSelectionKind(C: LLVMComdatRef) -> LLVMComdatSelectionKind;
+    pub fn LLVMSetComdatSelectionKind(C: LLVMComdatRef, Kind: LLVMComdatSelectionKind);
+    pub fn LLVMBasicBlockAsValue(BB: LLVMBasicBlockRef) -> LLVMValueRef;
+    pub fn LLVMGetBasicBlockName(BB: LLVMBasicBlockRef) -> *const c_char;
+    pub fn LLVMGetBasicBlockTerminator(BB: LLVMBasicBlockRef) -> LLVMValueRef;
+    pub fn LLVMGetLastInstruction(BB: LLVMBasicBlockRef) -> LLVMValueRef;
+    pub fn LLVMDeleteBasicBlock(BB: LLVMBasicBlockRef);
+    pub fn LLVMRemoveBasicBlockFromParent(BB: LLVMBasicBlockRef);
+    pub fn LLVMMoveBasicBlockBefore(BB: LLVMBasicBlockRef, MovePos: LLVMBasicBlockRef);
+    pub fn LLVMMoveBasicBlockAfter(BB: LLVMBasicBlockRef, MovePos: LLVMBasicBlockRef);
+    pub fn LLVMInsertBasicBlock(InsertBeforeBB: LLVMBasicBlockRef, Name: *const c_char) -> LLVMBasicBlockRef;
+    pub fn LLVMInsertBasicBlockInContext(C: LLVMContextRef, BB: LLVMBasicBlockRef, Name: *const c_char) -> LLVMBasicBlockRef;
+    pub fn LLVMGetFirstUse(Val: LLVMValueRef) -> LLVMUseRef;
+    pub fn LLVMGetNextUse(U: LLVMUseRef) -> LLVMUseRef;
+    pub fn LLVMGetUser(U: LLVMUseRef) -> LLVMValueRef;
+    pub fn LLVMClearInsertionPosition(Builder: LLVMBuilderRef);
+    pub fn LLVMInsertIntoBuilderWithName(Builder: LLVMBuilderRef, Instr: LLVMValueRef, Name: *const c_char);
+    pub fn LLVMGetCurrentDebugLocation2(Builder: LLVMBuilderRef) -> LLVMMetadataRef;
+    pub fn LLVMSetCurrentDebugLocation2(Builder: LLVMBuilderRef, Loc: LLVMMetadataRef);
+    pub fn LLVMBuildUDiv(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildSRem(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildURem(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFDiv(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFRem(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildShl(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildLShr(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildAShr(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildXor(B: LLVMBuilderRef, LHS: LLVMValueRef, RHS: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildNeg(B: LLVMBuilderRef, V: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildNot(B: LLVMBuilderRef, V: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFNeg(B: LLVMBuilderRef, V: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildTrunc(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildZExt(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildSExt(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFPToUI(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFPToSI(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildSIToFP(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFPTrunc(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildFPExt(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildPtrToInt(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildIntToPtr(B: LLVMBuilderRef, Val: LLVMValueRef, DestTy: LLVMTypeRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildExtractElement(B: LLVMBuilderRef, VecVal: LLVMValueRef, Index: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildInsertElement(B: LLVMBuilderRef, VecVal: LLVMValueRef, EltVal: LLVMValueRef, Index: LLVMValueRef, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMBuildCall2(B: LLVMBuilderRef, Ty: LLVMTypeRef, Fn: LLVMValueRef, Args: *mut LLVMValueRef, NumArgs: c_uint, Name: *const c_char) -> LLVMValueRef;
+    pub fn LLVMGetTypeKind(Ty: LLVMTypeRef) -> LLVMTypeKind;
+    pub fn LLVMGetVectorSize(VectorTy: LLVMTypeRef) -> c_uint;
+    pub fn LLVMGetIntTypeWidth(IntegerTy: LLVMTypeRef) -> c_uint;
+    pub fn LLVMIsAFunction(Val: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetUndef(Ty: LLVMTypeRef) -> LLVMValueRef;
+    pub fn LLVMReplaceAllUsesWith(OldVal: LLVMValueRef, NewVal: LLVMValueRef);
+    pub fn LLVMCountIncoming(PhiNode: LLVMValueRef) -> c_uint;
+    pub fn LLVMGetIncomingValue(PhiNode: LLVMValueRef, Index: c_uint) -> LLVMValueRef;
+    pub fn LLVMGetIncomingBlock(PhiNode: LLVMValueRef, Index: c_uint) -> LLVMBasicBlockRef;
+    pub fn LLVMIsAPHINode(Val: LLVMValueRef) -> LLVMValueRef;
+    pub fn LLVMGetGlobalParent(Global: LLVMValueRef) -> LLVMModuleRef;
+    pub fn LLVMPrintValueToString(Val: LLVMValueRef) -> *mut c_char;
+    pub fn LLVMContextSetDiagnosticHandler(C: LLVMContextRef, Handler: LLVMDiagnosticHandler, DiagnosticContext: *mut c_void);
+    pub fn LLVMGetDiagInfoDescription(DI: LLVMDiagnosticInfoRef) -> *mut c_char;
+    pub fn LLVMGetDiagInfoSeverity(DI: LLVMDiagnosticInfoRef) -> LLVMDiagnosticSeverity;
+    pub fn LLVMContextSetDiscardValueNames(C: LLVMContextRef, Discard: LLVMBool);
+    pub fn LLVMContextShouldDiscardValueNames(C: LLVMContextRef) -> LLVMBool;
+    pub fn LLVMInstallFatalErrorHandler(Handler: LLVMFatalErrorHandler);
+    pub fn LLVMResetFatalErrorHandler();
+    pub fn LLVMRunPasses(M: LLVMModuleRef, Passes: *const c_char, TM: LLVMTargetMachineRef, Options: LLVMPassBuilderOptionsRef) -> LLVMErrorRef;
+    pub fn LLVMCreatePassBuilderOptions() -> LLVMPassBuilderOptionsRef;
+    pub fn LLVMDisposePassBuilderOptions(Options: LLVMPassBuilderOptionsRef);
+    pub fn LLVMPassBuilderOptionsSetVerifyEach(Options: LLVMPassBuilderOptionsRef, VerifyEach: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetDebugLogging(Options: LLVMPassBuilderOptionsRef, DebugLogging: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetLoopInterleaving(Options: LLVMPassBuilderOptionsRef, LoopInterleaving: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetLoopVectorization(Options: LLVMPassBuilderOptionsRef, LoopVectorization: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetSLPVectorization(Options: LLVMPassBuilderOptionsRef, SLPVectorization: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetLoopUnrolling(Options: LLVMPassBuilderOptionsRef, LoopUnrolling: LLVMBool);
+    pub fn LLVMPassBuilderOptionsSetMergeFunctions(Options: LLVMPassBuilderOptionsRef, MergeFunctions: LLVMBool);
+    pub fn LLVMGetErrorMessage(Err: LLVMErrorRef) -> *mut c_char;
+    pub fn LLVMDisposeErrorMessage(ErrMsg: *mut c_char);
+    pub fn LLVMContextGetDiagnosticHandler(C: LLVMContextRef) -> LLVMDiagnosticHandler;
+    pub fn LLVMContextGetDiagnosticContext(C: LLVMContextRef) -> *mut c_void;
+    pub fn LLVMGetPointerAddressSpace(PointerTy: LLVMTypeRef) -> c_uint;
+    pub fn LLVM_VerifyFunction(Fn: LLVMValueRef, OutMessage: *mut *mut c_char) -> LLVMBool;
+    pub fn LLVM_MoveInstructionAfter(Inst: LLVMValueRef, MovePos: LLVMValueRef);
+    pub fn LLVM_MoveInstructionBefore(Inst: LLVMValueRef, MovePos: LLVMValueRef);
+    pub fn LLVMGetSubprogram(Func: LLVMValueRef) -> LLVMMetadataRef;
+    pub fn LLVMDIBuilderCreateDebugLocation(Ctx: LLVMContextRef, Line: c_uint, Column: c_uint, Scope: LLVMMetadataRef, InlinedAt: LLVMMetadataRef) -> LLVMMetadataRef;
 }
@@ -1,18 +1,65 @@
 use ffi::{
+    LLVMAliasGetAliasee,
+    LLVMAliasSetAliasee,
+    LLVMDeleteGlobal,
+    LLVMGetGlobalIFuncResolver,
+    LLVMGetInitializer,
+    LLVMGetThreadLocalMode,
+    LLVMGlobalGetValueType,
+    LLVMIsExternallyInitialized,
+    LLVMIsGlobalConstant,
+    LLVMIsThreadLocal,
+    LLVMSetExternallyInitialized,
+    LLVMSetGlobalConstant,
+    LLVMSetGlobalIFuncResolver,
     LLVMSetInitializer,
+    LLVMSetThreadLocal,
+    LLVMSetThreadLocalMode,
+    LLVMThreadLocalMode,
     LLVMTypeOf,
     LLVMValueRef,
 };
+use module::Function;
 use types::Type;
 use Value;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThreadLocalMode {
+    NotThreadLocal,
+    GeneralDynamic,
+    LocalDynamic,
+    InitialExec,
+    LocalExec,
+}
+
+impl ThreadLocalMode {
+    fn as_raw(&self) -> LLVMThreadLocalMode {
+        match *self {
+            ThreadLocalMode::NotThreadLocal => LLVMThreadLocalMode::LLVMNotThreadLocal,
+            ThreadLocalMode::GeneralDynamic => LLVMThreadLocalMode::LLVMGeneralDynamicTLSModel,
+            ThreadLocalMode::LocalDynamic => LLVMThreadLocalMode::LLVMLocalDynamicTLSModel,
+            ThreadLocalMode::InitialExec => LLVMThreadLocalMode::LLVMInitialExecTLSModel,
+            ThreadLocalMode::LocalExec => LLVMThreadLocalMode::LLVMLocalExecTLSModel,
+        }
+    }
+
+    fn from_raw(mode: LLVMThreadLocalMode) -> Self {
+        match mode {
+            LLVMThreadLocalMode::LLVMNotThreadLocal => ThreadLocalMode::NotThreadLocal,
+            LLVMThreadLocalMode::LLVMGeneralDynamicTLSModel => ThreadLocalMode::GeneralDynamic,
+            LLVMThreadLocalMode::LLVMLocalDynamicTLSModel => ThreadLocalMode::LocalDynamic,
+            LLVMThreadLocalMode::LLVMInitialExecTLSModel => ThreadLocalMode::InitialExec,
+            LLVMThreadLocalMode::LLVMLocalExecTLSModel => ThreadLocalMode::LocalExec,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GlobalVariable(LLVMValueRef);
 
 impl GlobalVariable {
     pub fn set_initializer(&self, constant_val: &Value) {
-        // FIXME: wrong assertion.
-        //debug_assert_eq!(self.get_type(), constant_val.get_type(), "the type of constant_val should match the type of global variable");
+        debug_assert_eq!(self.value_type(), constant_val.get_type(), "the type of constant_val should match the type of global variable");
         unsafe {
             LLVMSetInitializer(self.as_raw(), constant_val.as_raw());
         }
@@ -28,13 +75,158 @@ impl GlobalVariable {
         }
     }
 
+    pub fn delete(self) {
+        unsafe {
+            LLVMDeleteGlobal(self.as_raw());
+        }
+    }
+
     pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
         Self(value)
     }
 
+    pub fn get_thread_local_mode(&self) -> ThreadLocalMode {
+        unsafe {
+            ThreadLocalMode::from_raw(LLVMGetThreadLocalMode(self.as_raw()))
+        }
+    }
+
+    // NOTE: this is the type of the pointer to the global variable, see value_type() for the type
+    // of its content.
     pub fn get_type(&self) -> Type {
         unsafe {
             Type::from_raw(LLVMTypeOf(self.as_raw()))
         }
     }
+
+    pub fn initializer(&self) -> Option<Value> {
+        unsafe {
+            let value = LLVMGetInitializer(self.as_raw());
+            if value.is_null() {
+                return None;
+            }
+            Some(Value::from_raw(value))
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        unsafe {
+            LLVMIsGlobalConstant(self.as_raw()) != 0
+        }
+    }
+
+    pub fn is_externally_initialized(&self) -> bool {
+        unsafe {
+            LLVMIsExternallyInitialized(self.as_raw()) != 0
+        }
+    }
+
+    pub fn is_thread_local(&self) -> bool {
+        unsafe {
+            LLVMIsThreadLocal(self.as_raw()) != 0
+        }
+    }
+
+    pub fn set_constant(&self, is_constant: bool) {
+        unsafe {
+            LLVMSetGlobalConstant(self.as_raw(), is_constant as i32);
+        }
+    }
+
+    pub fn set_externally_initialized(&self, is_externally_initialized: bool) {
+        unsafe {
+            LLVMSetExternallyInitialized(self.as_raw(), is_externally_initialized as i32);
+        }
+    }
+
+    pub fn set_thread_local(&self, is_thread_local: bool) {
+        unsafe {
+            LLVMSetThreadLocal(self.as_raw(), is_thread_local as i32);
+        }
+    }
+
+    pub fn set_thread_local_mode(&self, mode: ThreadLocalMode) {
+        unsafe {
+            LLVMSetThreadLocalMode(self.as_raw(), mode.as_raw());
+        }
+    }
+
+    pub fn value_type(&self) -> Type {
+        unsafe {
+            Type::from_raw(LLVMGlobalGetValueType(self.as_raw()))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GlobalAlias(LLVMValueRef);
+
+impl GlobalAlias {
+    pub fn aliasee(&self) -> Value {
+        unsafe {
+            Value::from_raw(LLVMAliasGetAliasee(self.as_raw()))
+        }
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid global alias.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        Self(value)
+    }
+
+    pub fn set_aliasee(&self, aliasee: &Value) {
+        unsafe {
+            LLVMAliasSetAliasee(self.as_raw(), aliasee.as_raw());
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GlobalIFunc(LLVMValueRef);
+
+impl GlobalIFunc {
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid global ifunc.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        Self(value)
+    }
+
+    // NOTE: the resolver is not necessarily a function, e.g. a bitcast of a function.
+    pub fn resolver(&self) -> Option<Value> {
+        unsafe {
+            let resolver = LLVMGetGlobalIFuncResolver(self.as_raw());
+            if resolver.is_null() {
+                return None;
+            }
+            Some(Value::from_raw(resolver))
+        }
+    }
+
+    pub fn set_resolver(&self, resolver: &Function) {
+        unsafe {
+            LLVMSetGlobalIFuncResolver(self.as_raw(), resolver.as_raw());
+        }
+    }
 }
@@ -0,0 +1,299 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::c_uint;
+
+use comdat::Comdat;
+use ffi::{
+    LLVMDLLStorageClass,
+    LLVMGetAlignment,
+    LLVMGetComdat,
+    LLVMGetDLLStorageClass,
+    LLVMGetLinkage,
+    LLVMGetSection,
+    LLVMGetUnnamedAddress,
+    LLVMGetVisibility,
+    LLVMIsDeclaration,
+    LLVMLinkage,
+    LLVMSetAlignment,
+    LLVMSetComdat,
+    LLVMSetDLLStorageClass,
+    LLVMSetLinkage,
+    LLVMSetSection,
+    LLVMSetUnnamedAddress,
+    LLVMSetVisibility,
+    LLVMUnnamedAddr,
+    LLVMValueRef,
+    LLVMVisibility,
+};
+use global::{GlobalAlias, GlobalIFunc, GlobalVariable};
+use module::Function;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Linkage {
+    External,
+    AvailableExternally,
+    LinkOnceAny,
+    LinkOnceODR,
+    WeakAny,
+    WeakODR,
+    Appending,
+    Internal,
+    Private,
+    ExternalWeak,
+    Common,
+}
+
+impl Linkage {
+    fn as_raw(&self) -> LLVMLinkage {
+        match *self {
+            Linkage::External => LLVMLinkage::LLVMExternalLinkage,
+            Linkage::AvailableExternally => LLVMLinkage::LLVMAvailableExternallyLinkage,
+            Linkage::LinkOnceAny => LLVMLinkage::LLVMLinkOnceAnyLinkage,
+            Linkage::LinkOnceODR => LLVMLinkage::LLVMLinkOnceODRLinkage,
+            Linkage::WeakAny => LLVMLinkage::LLVMWeakAnyLinkage,
+            Linkage::WeakODR => LLVMLinkage::LLVMWeakODRLinkage,
+            Linkage::Appending => LLVMLinkage::LLVMAppendingLinkage,
+            Linkage::Internal => LLVMLinkage::LLVMInternalLinkage,
+            Linkage::Private => LLVMLinkage::LLVMPrivateLinkage,
+            Linkage::ExternalWeak => LLVMLinkage::LLVMExternalWeakLinkage,
+            Linkage::Common => LLVMLinkage::LLVMCommonLinkage,
+        }
+    }
+
+    fn from_raw(linkage: LLVMLinkage) -> Self {
+        match linkage {
+            LLVMLinkage::LLVMExternalLinkage => Linkage::External,
+            LLVMLinkage::LLVMAvailableExternallyLinkage => Linkage::AvailableExternally,
+            LLVMLinkage::LLVMLinkOnceAnyLinkage => Linkage::LinkOnceAny,
+            LLVMLinkage::LLVMLinkOnceODRLinkage => Linkage::LinkOnceODR,
+            LLVMLinkage::LLVMWeakAnyLinkage => Linkage::WeakAny,
+            LLVMLinkage::LLVMWeakODRLinkage => Linkage::WeakODR,
+            LLVMLinkage::LLVMAppendingLinkage => Linkage::Appending,
+            LLVMLinkage::LLVMInternalLinkage => Linkage::Internal,
+            LLVMLinkage::LLVMPrivateLinkage => Linkage::Private,
+            LLVMLinkage::LLVMExternalWeakLinkage => Linkage::ExternalWeak,
+            LLVMLinkage::LLVMCommonLinkage => Linkage::Common,
+            // These linkages are obsolete and never returned by LLVM.
+            LLVMLinkage::LLVMLinkOnceODRAutoHideLinkage |
+            LLVMLinkage::LLVMDLLImportLinkage |
+            LLVMLinkage::LLVMDLLExportLinkage |
+            LLVMLinkage::LLVMGhostLinkage |
+            LLVMLinkage::LLVMLinkerPrivateLinkage |
+            LLVMLinkage::LLVMLinkerPrivateWeakLinkage => unreachable!("obsolete linkage"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visibility {
+    Default,
+    Hidden,
+    Protected,
+}
+
+impl Visibility {
+    fn as_raw(&self) -> LLVMVisibility {
+        match *self {
+            Visibility::Default => LLVMVisibility::LLVMDefaultVisibility,
+            Visibility::Hidden => LLVMVisibility::LLVMHiddenVisibility,
+            Visibility::Protected => LLVMVisibility::LLVMProtectedVisibility,
+        }
+    }
+
+    fn from_raw(visibility: LLVMVisibility) -> Self {
+        match visibility {
+            LLVMVisibility::LLVMDefaultVisibility => Visibility::Default,
+            LLVMVisibility::LLVMHiddenVisibility => Visibility::Hidden,
+            LLVMVisibility::LLVMProtectedVisibility => Visibility::Protected,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DLLStorageClass {
+    Default,
+    DLLImport,
+    DLLExport,
+}
+
+impl DLLStorageClass {
+    fn as_raw(&self) -> LLVMDLLStorageClass {
+        match *self {
+            DLLStorageClass::Default => LLVMDLLStorageClass::LLVMDefaultStorageClass,
+            DLLStorageClass::DLLImport => LLVMDLLStorageClass::LLVMDLLImportStorageClass,
+            DLLStorageClass::DLLExport => LLVMDLLStorageClass::LLVMDLLExportStorageClass,
+        }
+    }
+
+    fn from_raw(storage_class: LLVMDLLStorageClass) -> Self {
+        match storage_class {
+            LLVMDLLStorageClass::LLVMDefaultStorageClass => DLLStorageClass::Default,
+            LLVMDLLStorageClass::LLVMDLLImportStorageClass => DLLStorageClass::DLLImport,
+            LLVMDLLStorageClass::LLVMDLLExportStorageClass => DLLStorageClass::DLLExport,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnnamedAddr {
+    None,
+    Local,
+    Global,
+}
+
+impl UnnamedAddr {
+    fn as_raw(&self) -> LLVMUnnamedAddr {
+        match *self {
+            UnnamedAddr::None => LLVMUnnamedAddr::LLVMNoUnnamedAddr,
+            UnnamedAddr::Local => LLVMUnnamedAddr::LLVMLocalUnnamedAddr,
+            UnnamedAddr::Global => LLVMUnnamedAddr::LLVMGlobalUnnamedAddr,
+        }
+    }
+
+    fn from_raw(unnamed_addr: LLVMUnnamedAddr) -> Self {
+        match unnamed_addr {
+            LLVMUnnamedAddr::LLVMNoUnnamedAddr => UnnamedAddr::None,
+            LLVMUnnamedAddr::LLVMLocalUnnamedAddr => UnnamedAddr::Local,
+            LLVMUnnamedAddr::LLVMGlobalUnnamedAddr => UnnamedAddr::Global,
+        }
+    }
+}
+
+pub trait GlobalValue {
+    fn as_raw(&self) -> LLVMValueRef;
+
+    fn get_dll_storage_class(&self) -> DLLStorageClass {
+        unsafe {
+            DLLStorageClass::from_raw(LLVMGetDLLStorageClass(self.as_raw()))
+        }
+    }
+
+    fn get_linkage(&self) -> Linkage {
+        unsafe {
+            Linkage::from_raw(LLVMGetLinkage(self.as_raw()))
+        }
+    }
+
+    fn get_unnamed_addr(&self) -> UnnamedAddr {
+        unsafe {
+            UnnamedAddr::from_raw(LLVMGetUnnamedAddress(self.as_raw()))
+        }
+    }
+
+    fn get_visibility(&self) -> Visibility {
+        unsafe {
+            Visibility::from_raw(LLVMGetVisibility(self.as_raw()))
+        }
+    }
+
+    fn is_declaration(&self) -> bool {
+        unsafe {
+            LLVMIsDeclaration(self.as_raw()) != 0
+        }
+    }
+
+    fn set_dll_storage_class(&self, storage_class: DLLStorageClass) {
+        unsafe {
+            LLVMSetDLLStorageClass(self.as_raw(), storage_class.as_raw());
+        }
+    }
+
+    fn set_linkage(&self, linkage: Linkage) {
+        unsafe {
+            LLVMSetLinkage(self.as_raw(), linkage.as_raw());
+        }
+    }
+
+    fn set_unnamed_addr(&self, unnamed_addr: UnnamedAddr) {
+        unsafe {
+            LLVMSetUnnamedAddress(self.as_raw(), unnamed_addr.as_raw());
+        }
+    }
+
+    fn set_visibility(&self, visibility: Visibility) {
+        unsafe {
+            LLVMSetVisibility(self.as_raw(), visibility.as_raw());
+        }
+    }
+}
+
+// NOTE: functions, global variables and ifuncs, but not aliases.
+pub trait GlobalObject: GlobalValue {
+    fn get_alignment(&self) -> usize {
+        unsafe {
+            LLVMGetAlignment(self.as_raw()) as usize
+        }
+    }
+
+    fn get_comdat(&self) -> Option<Comdat> {
+        unsafe {
+            let comdat = LLVMGetComdat(self.as_raw());
+            if comdat.is_null() {
+                return None;
+            }
+            Some(Comdat::from_raw(comdat))
+        }
+    }
+
+    fn get_section(&self) -> Option<String> {
+        unsafe {
+            let section = LLVMGetSection(self.as_raw());
+            if section.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(section).to_str().expect("to_str").to_string())
+        }
+    }
+
+    fn set_alignment(&self, alignment: usize) {
+        debug_assert!(alignment == 0 || alignment.is_power_of_two());
+        unsafe {
+            LLVMSetAlignment(self.as_raw(), alignment as c_uint);
+        }
+    }
+
+    fn set_comdat(&self, comdat: &Comdat) {
+        unsafe {
+            LLVMSetComdat(self.as_raw(), comdat.as_raw());
+        }
+    }
+
+    fn set_section(&self, section: &str) {
+        let cstring = CString::new(section).expect("cstring");
+        unsafe {
+            LLVMSetSection(self.as_raw(), cstring.as_ptr());
+        }
+    }
+}
+
+impl GlobalObject for Function {
+}
+
+impl GlobalObject for GlobalIFunc {
+}
+
+impl GlobalObject for GlobalVariable {
+}
+
+impl GlobalValue for Function {
+    fn as_raw(&self) -> LLVMValueRef {
+        Function::as_raw(self)
+    }
+}
+
+impl GlobalValue for GlobalAlias {
+    fn as_raw(&self) -> LLVMValueRef {
+        GlobalAlias::as_raw(self)
+    }
+}
+
+impl GlobalValue for GlobalIFunc {
+    fn as_raw(&self) -> LLVMValueRef {
+        GlobalIFunc::as_raw(self)
+    }
+}
+
+impl GlobalValue for GlobalVariable {
+    fn as_raw(&self) -> LLVMValueRef {
+        GlobalVariable::as_raw(self)
+    }
+}
@@ -0,0 +1,295 @@
+use std::os::raw::c_uint;
+
+use ffi::{
+    LLVMGetInstructionOpcode,
+    LLVMGetInstructionParent,
+    LLVMGetNextInstruction,
+    LLVMGetNumSuccessors,
+    LLVMGetPreviousInstruction,
+    LLVMGetSuccessor,
+    LLVMInstructionClone,
+    LLVMInstructionEraseFromParent,
+    LLVMInstructionRemoveFromParent,
+    LLVMIsATerminatorInst,
+    LLVMOpcode,
+    LLVMSetSuccessor,
+    LLVMValueRef,
+    LLVM_MoveInstructionAfter,
+    LLVM_MoveInstructionBefore,
+};
+use basic_block::BasicBlock;
+use value::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Ret,
+    Br,
+    Switch,
+    IndirectBr,
+    Invoke,
+    Unreachable,
+    CallBr,
+    FNeg,
+    Add,
+    FAdd,
+    Sub,
+    FSub,
+    Mul,
+    FMul,
+    UDiv,
+    SDiv,
+    FDiv,
+    URem,
+    SRem,
+    FRem,
+    Shl,
+    LShr,
+    AShr,
+    And,
+    Or,
+    Xor,
+    Alloca,
+    Load,
+    Store,
+    GetElementPtr,
+    Trunc,
+    ZExt,
+    SExt,
+    FPToUI,
+    FPToSI,
+    UIToFP,
+    SIToFP,
+    FPTrunc,
+    FPExt,
+    PtrToInt,
+    IntToPtr,
+    BitCast,
+    AddrSpaceCast,
+    ICmp,
+    FCmp,
+    Phi,
+    Call,
+    Select,
+    UserOp1,
+    UserOp2,
+    VAArg,
+    ExtractElement,
+    InsertElement,
+    ShuffleVector,
+    ExtractValue,
+    InsertValue,
+    Freeze,
+    Fence,
+    AtomicCmpXchg,
+    AtomicRMW,
+    Resume,
+    LandingPad,
+    CleanupRet,
+    CatchRet,
+    CatchPad,
+    CleanupPad,
+    CatchSwitch,
+}
+
+impl Opcode {
+    fn from_raw(opcode: LLVMOpcode) -> Self {
+        match opcode {
+            LLVMOpcode::LLVMRet => Opcode::Ret,
+            LLVMOpcode::LLVMBr => Opcode::Br,
+            LLVMOpcode::LLVMSwitch => Opcode::Switch,
+            LLVMOpcode::LLVMIndirectBr => Opcode::IndirectBr,
+            LLVMOpcode::LLVMInvoke => Opcode::Invoke,
+            LLVMOpcode::LLVMUnreachable => Opcode::Unreachable,
+            LLVMOpcode::LLVMCallBr => Opcode::CallBr,
+            LLVMOpcode::LLVMFNeg => Opcode::FNeg,
+            LLVMOpcode::LLVMAdd => Opcode::Add,
+            LLVMOpcode::LLVMFAdd => Opcode::FAdd,
+            LLVMOpcode::LLVMSub => Opcode::Sub,
+            LLVMOpcode::LLVMFSub => Opcode::FSub,
+            LLVMOpcode::LLVMMul => Opcode::Mul,
+            LLVMOpcode::LLVMFMul => Opcode::FMul,
+            LLVMOpcode::LLVMUDiv => Opcode::UDiv,
+            LLVMOpcode::LLVMSDiv => Opcode::SDiv,
+            LLVMOpcode::LLVMFDiv => Opcode::FDiv,
+            LLVMOpcode::LLVMURem => Opcode::URem,
+            LLVMOpcode::LLVMSRem => Opcode::SRem,
+            LLVMOpcode::LLVMFRem => Opcode::FRem,
+            LLVMOpcode::LLVMShl => Opcode::Shl,
+            LLVMOpcode::LLVMLShr => Opcode::LShr,
+            LLVMOpcode::LLVMAShr => Opcode::AShr,
+            LLVMOpcode::LLVMAnd => Opcode::And,
+            LLVMOpcode::LLVMOr => Opcode::Or,
+            LLVMOpcode::LLVMXor => Opcode::Xor,
+            LLVMOpcode::LLVMAlloca => Opcode::Alloca,
+            LLVMOpcode::LLVMLoad => Opcode::Load,
+            LLVMOpcode::LLVMStore => Opcode::Store,
+            LLVMOpcode::LLVMGetElementPtr => Opcode::GetElementPtr,
+            LLVMOpcode::LLVMTrunc => Opcode::Trunc,
+            LLVMOpcode::LLVMZExt => Opcode::ZExt,
+            LLVMOpcode::LLVMSExt => Opcode::SExt,
+            LLVMOpcode::LLVMFPToUI => Opcode::FPToUI,
+            LLVMOpcode::LLVMFPToSI => Opcode::FPToSI,
+            LLVMOpcode::LLVMUIToFP => Opcode::UIToFP,
+            LLVMOpcode::LLVMSIToFP => Opcode::SIToFP,
+            LLVMOpcode::LLVMFPTrunc => Opcode::FPTrunc,
+            LLVMOpcode::LLVMFPExt => Opcode::FPExt,
+            LLVMOpcode::LLVMPtrToInt => Opcode::PtrToInt,
+            LLVMOpcode::LLVMIntToPtr => Opcode::IntToPtr,
+            LLVMOpcode::LLVMBitCast => Opcode::BitCast,
+            LLVMOpcode::LLVMAddrSpaceCast => Opcode::AddrSpaceCast,
+            LLVMOpcode::LLVMICmp => Opcode::ICmp,
+            LLVMOpcode::LLVMFCmp => Opcode::FCmp,
+            LLVMOpcode::LLVMPHI => Opcode::Phi,
+            LLVMOpcode::LLVMCall => Opcode::Call,
+            LLVMOpcode::LLVMSelect => Opcode::Select,
+            LLVMOpcode::LLVMUserOp1 => Opcode::UserOp1,
+            LLVMOpcode::LLVMUserOp2 => Opcode::UserOp2,
+            LLVMOpcode::LLVMVAArg => Opcode::VAArg,
+            LLVMOpcode::LLVMExtractElement => Opcode::ExtractElement,
+            LLVMOpcode::LLVMInsertElement => Opcode::InsertElement,
+            LLVMOpcode::LLVMShuffleVector => Opcode::ShuffleVector,
+            LLVMOpcode::LLVMExtractValue => Opcode::ExtractValue,
+            LLVMOpcode::LLVMInsertValue => Opcode::InsertValue,
+            LLVMOpcode::LLVMFreeze => Opcode::Freeze,
+            LLVMOpcode::LLVMFence => Opcode::Fence,
+            LLVMOpcode::LLVMAtomicCmpXchg => Opcode::AtomicCmpXchg,
+            LLVMOpcode::LLVMAtomicRMW => Opcode::AtomicRMW,
+            LLVMOpcode::LLVMResume => Opcode::Resume,
+            LLVMOpcode::LLVMLandingPad => Opcode::LandingPad,
+            LLVMOpcode::LLVMCleanupRet => Opcode::CleanupRet,
+            LLVMOpcode::LLVMCatchRet => Opcode::CatchRet,
+            LLVMOpcode::LLVMCatchPad => Opcode::CatchPad,
+            LLVMOpcode::LLVMCleanupPad => Opcode::CleanupPad,
+            LLVMOpcode::LLVMCatchSwitch => Opcode::CatchSwitch,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction(LLVMValueRef);
+
+impl Instruction {
+    /// # Safety
+    ///
+    /// `value` must be a valid instruction.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        Self(value)
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    // NOTE: this is not Clone::clone() which only copies the reference to the instruction.
+    pub fn clone_instruction(&self) -> Instruction {
+        unsafe {
+            Instruction(LLVMInstructionClone(self.as_raw()))
+        }
+    }
+
+    pub fn erase_from_parent(self) {
+        unsafe {
+            LLVMInstructionEraseFromParent(self.as_raw());
+        }
+    }
+
+    pub fn get_successor(&self, index: usize) -> BasicBlock {
+        assert!(index < self.successor_count(), "successor index out of bounds");
+        unsafe {
+            BasicBlock::from_raw(LLVMGetSuccessor(self.as_raw(), index as c_uint))
+        }
+    }
+
+    pub fn is_terminator(&self) -> bool {
+        unsafe {
+            !LLVMIsATerminatorInst(self.as_raw()).is_null()
+        }
+    }
+
+    // NOTE: the instruction keeps its name. It is inserted if it has no parent.
+    pub fn move_after(&self, instruction: &Instruction) {
+        if instruction == self {
+            return;
+        }
+        unsafe {
+            LLVM_MoveInstructionAfter(self.as_raw(), instruction.as_raw());
+        }
+    }
+
+    pub fn move_before(&self, instruction: &Instruction) {
+        if instruction == self {
+            return;
+        }
+        unsafe {
+            LLVM_MoveInstructionBefore(self.as_raw(), instruction.as_raw());
+        }
+    }
+
+    pub fn next(&self) -> Option<Instruction> {
+        unsafe {
+            let instruction = LLVMGetNextInstruction(self.as_raw());
+            if instruction.is_null() {
+                return None;
+            }
+            Some(Instruction(instruction))
+        }
+    }
+
+    pub fn opcode(&self) -> Opcode {
+        unsafe {
+            Opcode::from_raw(LLVMGetInstructionOpcode(self.as_raw()))
+        }
+    }
+
+    pub fn parent(&self) -> Option<BasicBlock> {
+        unsafe {
+            let basic_block = LLVMGetInstructionParent(self.as_raw());
+            if basic_block.is_null() {
+                return None;
+            }
+            Some(BasicBlock::from_raw(basic_block))
+        }
+    }
+
+    pub fn previous(&self) -> Option<Instruction> {
+        unsafe {
+            let instruction = LLVMGetPreviousInstruction(self.as_raw());
+            if instruction.is_null() {
+                return None;
+            }
+            Some(Instruction(instruction))
+        }
+    }
+
+    pub fn remove_from_parent(&self) {
+        unsafe {
+            LLVMInstructionRemoveFromParent(self.as_raw());
+        }
+    }
+
+    pub fn set_successor(&self, index: usize, basic_block: &BasicBlock) {
+        assert!(index < self.successor_count(), "successor index out of bounds");
+        unsafe {
+            LLVMSetSuccessor(self.as_raw(), index as c_uint, basic_block.as_raw());
+        }
+    }
+
+    pub fn successor_count(&self) -> usize {
+        assert!(self.is_terminator(), "successors are only available on terminator instructions");
+        unsafe {
+            LLVMGetNumSuccessors(self.as_raw()) as usize
+        }
+    }
+
+    pub fn successors(&self) -> Vec<BasicBlock> {
+        (0..self.successor_count())
+            .map(|index| self.get_successor(index))
+            .collect()
+    }
+}
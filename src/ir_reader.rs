@@ -0,0 +1,40 @@
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+use Context;
+use error::{Error, ParseDiagnostic, Result};
+use ffi::{
+    LLVMDisposeMessage,
+    LLVMParseIRInContext,
+};
+use memory_buffer::MemoryBuffer;
+use module::Module;
+
+impl Context {
+    pub fn parse_ir(&self, source: &str) -> Result<Module> {
+        self.parse_ir_buffer(MemoryBuffer::from_bytes(source.as_bytes(), "<string>"))
+    }
+
+    pub fn parse_ir_file(&self, filename: &str) -> Result<Module> {
+        self.parse_ir_buffer(MemoryBuffer::from_file(filename)?)
+    }
+
+    fn parse_ir_buffer(&self, buffer: MemoryBuffer) -> Result<Module> {
+        let mut module = ptr::null_mut();
+        let mut error = ptr::null_mut();
+        unsafe {
+            let result = LLVMParseIRInContext(self.as_raw(), buffer.as_raw(), &mut module, &mut error);
+            mem::forget(buffer);
+            if result != 0 {
+                let cstr = CStr::from_ptr(error);
+                let diagnostic = ParseDiagnostic::from_message(cstr.to_str().expect("error cstr"));
+                LLVMDisposeMessage(error);
+                Err(Error::ParseIR(diagnostic))
+            }
+            else {
+                Ok(Module::from_raw(module))
+            }
+        }
+    }
+}
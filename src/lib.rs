@@ -3,32 +3,59 @@
  */
 
 pub mod analysis;
+pub mod attribute;
 pub mod basic_block;
+pub mod bitcode;
 pub mod builder;
+pub mod calling_convention;
+pub mod comdat;
 pub mod context;
+pub mod control_flow;
+pub mod error;
 pub mod exec_engine;
+pub mod fatal_error;
 pub mod ffi;
 pub mod global;
+pub mod global_value;
+pub mod instruction;
+pub mod ir_reader;
+pub mod memory_buffer;
+pub mod metadata;
 pub mod module;
 pub mod pass_manager;
+pub mod ssa;
 pub mod target;
+pub mod thread_safe;
+pub mod typed_builder;
 pub mod types;
 pub mod value;
 
-pub use analysis::VerifierFailureAction;
+pub use analysis::{VerifierDiagnostic, VerifierFailureAction};
+pub use attribute::{Attribute, AttributeLoc, EnumAttribute, IntAttribute, TypeAttribute};
 pub use basic_block::BasicBlock;
-pub use builder::{Builder, IntPredicate, RealPredicate};
-pub use context::Context;
+pub use builder::{Builder, InsertionPointGuard, IntPredicate, RealPredicate};
+pub use calling_convention::CallingConvention;
+pub use comdat::{Comdat, ComdatSelectionKind};
+pub use context::{Context, Diagnostic, DiagnosticSeverity};
+pub use control_flow::Loop;
+pub use error::Error;
 pub use exec_engine::{ExecutionEngine, FunctionAddress, link_mcjit};
-pub use global::GlobalVariable;
+pub use fatal_error::{install_fatal_error_handler, reset_fatal_error_handler};
+pub use global::{GlobalAlias, GlobalIFunc, GlobalVariable, ThreadLocalMode};
+pub use global_value::{DLLStorageClass, GlobalObject, GlobalValue, Linkage, UnnamedAddr, Visibility};
+pub use instruction::{Instruction, Opcode};
+pub use memory_buffer::MemoryBuffer;
+pub use metadata::{Metadata, ModuleFlagBehavior};
 pub use module::Module;
-pub use pass_manager::{FunctionPassManager, ModulePassManager};
+pub use pass_manager::{FunctionPassManager, ModulePassManager, PassBuilderOptions};
+pub use ssa::{SsaBuilder, Variable};
 pub use target::{
     CodeGenFileType,
     CodeGenOptLevel,
     CodeModel,
     RelocMode,
     Target,
+    TargetTriple,
     get_default_target_triple,
     initialize_all_asm_parsers,
     initialize_all_asm_printers,
@@ -38,45 +65,46 @@ pub use target::{
     initialize_native_asm_printer,
     initialize_native_target,
 };
+pub use thread_safe::{ThreadSafeContext, ThreadSafeModule};
+pub use typed_builder::TypedBuilder;
 pub use value::Value;
+pub use value::typed::{FloatValue, FunctionValue, IntValue, PointerValue, VectorValue};
 
-use std::cell::Cell;
-use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::slice;
+use std::sync::Once;
 
-use ffi::LLVMShutdown;
-
-thread_local! {
-    static INITIALIZED: Cell<bool> = Cell::new(false);
-}
+static INITIALIZED: Once = Once::new();
 
+// NOTE: LLVM is initialized once per process and never shut down: LLVMShutdown() is process-wide and
+// LLVM cannot be used anymore after calling it, even on other threads.
 pub struct LLVM {
-    _phantom: PhantomData<*mut ()>,
+    _private: (),
 }
 
 fn assert_llvm_initialized() {
-    INITIALIZED.with(|initialized| {
-        if !initialized.get() {
-            panic!("LLVM not initialized: call llvm_init()");
-        }
-    });
+    if !INITIALIZED.is_completed() {
+        panic!("LLVM not initialized: call llvm_init()");
+    }
 }
 
+// Initialize LLVM and the native target. This can be called from multiple threads and multiple
+// times.
 pub fn llvm_init() -> LLVM {
-    INITIALIZED.with(|initialized| {
-        initialized.set(true);
+    INITIALIZED.call_once(|| {
+        link_mcjit();
+        assert!(!initialize_native_target(), "cannot initialize the native target");
+        assert!(!initialize_native_asm_printer(), "cannot initialize the native asm printer");
     });
     LLVM {
-        _phantom: PhantomData,
+        _private: (),
     }
 }
 
-impl Drop for LLVM {
-    fn drop(&mut self) {
-        unsafe {
-            LLVMShutdown();
-        }
-        INITIALIZED.with(|initialized| {
-            initialized.set(false);
-        });
+unsafe fn string_from_raw_parts(string: *const c_char, len: usize) -> String {
+    if string.is_null() {
+        return String::new();
     }
+    let bytes = slice::from_raw_parts(string as *const u8, len);
+    String::from_utf8_lossy(bytes).into_owned()
 }
@@ -0,0 +1,77 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::slice;
+
+use error::{Error, Result};
+use ffi::{
+    LLVMCreateMemoryBufferWithContentsOfFile,
+    LLVMCreateMemoryBufferWithMemoryRangeCopy,
+    LLVMDisposeMemoryBuffer,
+    LLVMDisposeMessage,
+    LLVMGetBufferSize,
+    LLVMGetBufferStart,
+    LLVMMemoryBufferRef,
+};
+
+pub struct MemoryBuffer(LLVMMemoryBufferRef);
+
+impl MemoryBuffer {
+    pub fn from_bytes(bytes: &[u8], name: &str) -> Self {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Self(LLVMCreateMemoryBufferWithMemoryRangeCopy(bytes.as_ptr() as *const _, bytes.len(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn from_file(filename: &str) -> Result<Self> {
+        let mut buffer = ptr::null_mut();
+        let mut error = ptr::null_mut();
+        let filename = CString::new(filename).expect("cstring");
+        unsafe {
+            if LLVMCreateMemoryBufferWithContentsOfFile(filename.as_ptr(), &mut buffer, &mut error) != 0 {
+                let cstr = CStr::from_ptr(error);
+                let read_error = cstr.to_str().expect("error cstr").to_string();
+                LLVMDisposeMessage(error);
+                Err(Error::Read(read_error))
+            }
+            else {
+                Ok(Self(buffer))
+            }
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `buffer` must be a valid memory buffer, which is disposed when the returned value is dropped.
+    pub unsafe fn from_raw(buffer: LLVMMemoryBufferRef) -> Self {
+        Self(buffer)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(LLVMGetBufferStart(self.as_raw()) as *const u8, self.len())
+        }
+    }
+
+    pub fn as_raw(&self) -> LLVMMemoryBufferRef {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe {
+            LLVMGetBufferSize(self.as_raw())
+        }
+    }
+}
+
+impl Drop for MemoryBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            LLVMDisposeMemoryBuffer(self.as_raw());
+        }
+    }
+}
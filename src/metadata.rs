@@ -0,0 +1,76 @@
+use std::os::raw::c_char;
+
+use {Context, Value};
+use ffi::{
+    LLVMMDNodeInContext2,
+    LLVMMDStringInContext2,
+    LLVMMetadataAsValue,
+    LLVMMetadataRef,
+    LLVMModuleFlagBehavior,
+    LLVMValueAsMetadata,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ModuleFlagBehavior {
+    Error,
+    Warning,
+    Require,
+    Override,
+    Append,
+    AppendUnique,
+}
+
+impl ModuleFlagBehavior {
+    pub(crate) fn as_raw(&self) -> LLVMModuleFlagBehavior {
+        match *self {
+            ModuleFlagBehavior::Error => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorError,
+            ModuleFlagBehavior::Warning => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorWarning,
+            ModuleFlagBehavior::Require => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorRequire,
+            ModuleFlagBehavior::Override => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorOverride,
+            ModuleFlagBehavior::Append => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorAppend,
+            ModuleFlagBehavior::AppendUnique => LLVMModuleFlagBehavior::LLVMModuleFlagBehaviorAppendUnique,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Metadata(LLVMMetadataRef);
+
+impl Metadata {
+    pub fn from_value(value: &Value) -> Self {
+        unsafe {
+            Self(LLVMValueAsMetadata(value.as_raw()))
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `metadata` must be a valid metadata.
+    pub unsafe fn from_raw(metadata: LLVMMetadataRef) -> Self {
+        Self(metadata)
+    }
+
+    pub fn as_raw(&self) -> LLVMMetadataRef {
+        self.0
+    }
+
+    pub fn as_value(&self, context: &Context) -> Value {
+        unsafe {
+            Value::from_raw(LLVMMetadataAsValue(context.as_raw(), self.as_raw()))
+        }
+    }
+}
+
+impl Context {
+    pub fn metadata_node(&self, elements: &[Metadata]) -> Metadata {
+        unsafe {
+            Metadata(LLVMMDNodeInContext2(self.as_raw(), elements.as_ptr() as *mut _, elements.len()))
+        }
+    }
+
+    pub fn metadata_string(&self, string: &str) -> Metadata {
+        unsafe {
+            Metadata(LLVMMDStringInContext2(self.as_raw(), string.as_ptr() as *const c_char, string.len()))
+        }
+    }
+}
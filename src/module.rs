@@ -1,34 +1,97 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
 
 use Context;
+use string_from_raw_parts;
 use basic_block::BasicBlock;
+use comdat::Comdat;
+use error::{Error, Result};
 use exec_engine::TargetData;
 use ffi::{
+    LLVMAddAlias2,
     LLVMAddFunction,
     LLVMAddGlobal,
+    LLVMAddGlobalIFunc,
+    LLVMAddModuleFlag,
+    LLVMAddNamedMetadataOperand,
     LLVMAppendBasicBlock,
     LLVMAppendBasicBlockInContext,
+    LLVMAppendModuleInlineAsm,
+    LLVMCloneModule,
+    LLVMContextGetDiagnosticContext,
+    LLVMContextGetDiagnosticHandler,
+    LLVMContextSetDiagnosticHandler,
+    LLVMCopyStringRepOfTargetData,
     LLVMCountBasicBlocks,
     LLVMCountParams,
     LLVMDeleteFunction,
+    LLVMDiagnosticHandler,
+    LLVMDiagnosticInfoRef,
+    LLVMDiagnosticSeverity,
+    LLVMDisposeMessage,
     LLVMDumpModule,
     LLVMDumpValue,
+    LLVMGetDataLayoutStr,
+    LLVMGetDiagInfoDescription,
+    LLVMGetDiagInfoSeverity,
     LLVMGetEntryBasicBlock,
+    LLVMGetFirstBasicBlock,
+    LLVMGetFirstFunction,
+    LLVMGetFirstGlobal,
+    LLVMGetFirstGlobalAlias,
+    LLVMGetFirstGlobalIFunc,
+    LLVMGetFirstParam,
+    LLVMGetGC,
+    LLVMGetLastBasicBlock,
+    LLVMGetModuleContext,
+    LLVMGetModuleFlag,
+    LLVMGetModuleIdentifier,
+    LLVMGetModuleInlineAsm,
     LLVMGetNamedFunction,
+    LLVMGetNamedGlobal,
+    LLVMGetNamedMetadataNumOperands,
+    LLVMGetNamedMetadataOperands,
+    LLVMGetNextBasicBlock,
+    LLVMGetNextFunction,
+    LLVMGetNextGlobal,
+    LLVMGetNextGlobalAlias,
+    LLVMGetNextGlobalIFunc,
+    LLVMGetNextParam,
+    LLVMGetOrInsertComdat,
     LLVMGetParam,
+    LLVMGetPointerAddressSpace,
+    LLVMGetReturnType,
+    LLVMGetSourceFileName,
+    LLVMGetTarget,
+    LLVMGetTypeByName2,
+    LLVMGetValueName2,
+    LLVMGlobalGetValueType,
+    LLVMLinkModules2,
+    LLVMMetadataAsValue,
     LLVMModuleCreateWithName,
     LLVMModuleCreateWithNameInContext,
     LLVMModuleRef,
+    LLVMPrintModuleToFile,
+    LLVMPrintModuleToString,
     LLVMSetDataLayout,
+    LLVMSetGC,
+    LLVMSetModuleIdentifier,
+    LLVMSetModuleInlineAsm2,
+    LLVMSetSourceFileName,
     LLVMSetTarget,
+    LLVMSetValueName2,
+    LLVMTypeOf,
+    LLVMValueAsMetadata,
     LLVMValueRef,
-    LLVMVerifyFunction,
 };
-use global::GlobalVariable;
+use global::{GlobalAlias, GlobalIFunc, GlobalVariable};
+use metadata::{Metadata, ModuleFlagBehavior};
 use target::TargetTriple;
 use types::Type;
 use value::Value;
-use VerifierFailureAction;
 
 pub struct Module(LLVMModuleRef);
 
@@ -39,6 +102,13 @@ impl Module {
         Self(module)
     }
 
+    /// # Safety
+    ///
+    /// `module` must be a valid module.
+    pub unsafe fn from_raw(module: LLVMModuleRef) -> Self {
+        Self(module)
+    }
+
     pub(crate) fn new_with_name_in_context(name: &str, context: &Context) -> Self {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -46,6 +116,14 @@ impl Module {
         }
     }
 
+    pub fn add_alias(&self, value_type: &Type, aliasee: &Value, name: &str) -> GlobalAlias {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            let address_space = LLVMGetPointerAddressSpace(LLVMTypeOf(aliasee.as_raw()));
+            GlobalAlias::from_raw(LLVMAddAlias2(self.as_raw(), value_type.as_raw(), address_space, aliasee.as_raw(), cstring.as_ptr()))
+        }
+    }
+
     pub fn add_function(&self, name: &str, function_type: Type) -> Function {
         let cstring = CString::new(name).expect("cstring");
         let value = unsafe { LLVMAddFunction(self.as_raw(), cstring.as_ptr(), function_type.as_raw()) };
@@ -59,6 +137,44 @@ impl Module {
         }
     }
 
+    pub fn add_ifunc(&self, function_type: &Type, resolver: &Function, name: &str) -> GlobalIFunc {
+        unsafe {
+            let address_space = LLVMGetPointerAddressSpace(LLVMTypeOf(resolver.as_raw()));
+            GlobalIFunc::from_raw(LLVMAddGlobalIFunc(self.as_raw(), name.as_ptr() as *const c_char, name.len(), function_type.as_raw(),
+                address_space, resolver.as_raw()))
+        }
+    }
+
+    pub fn add_module_flag(&self, behavior: ModuleFlagBehavior, key: &str, value: &Metadata) {
+        unsafe {
+            LLVMAddModuleFlag(self.as_raw(), behavior.as_raw(), key.as_ptr() as *const c_char, key.len(), value.as_raw());
+        }
+    }
+
+    pub fn add_named_metadata_operand(&self, name: &str, node: &Metadata) {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            let value = LLVMMetadataAsValue(LLVMGetModuleContext(self.as_raw()), node.as_raw());
+            LLVMAddNamedMetadataOperand(self.as_raw(), cstring.as_ptr(), value);
+        }
+    }
+
+    pub fn alias_count(&self) -> usize {
+        self.aliases().count()
+    }
+
+    pub fn aliases(&self) -> ModuleIter<'_, GlobalAlias> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstGlobalAlias(self.as_raw()), LLVMGetNextGlobalAlias, GlobalAlias::from_raw)
+        }
+    }
+
+    pub fn append_inline_asm(&self, asm: &str) {
+        unsafe {
+            LLVMAppendModuleInlineAsm(self.as_raw(), asm.as_ptr() as *const c_char, asm.len());
+        }
+    }
+
     pub fn as_raw(&self) -> LLVMModuleRef {
         self.0
     }
@@ -67,6 +183,50 @@ impl Module {
         unsafe { LLVMDumpModule(self.as_raw()) }
     }
 
+    pub fn function_count(&self) -> usize {
+        self.functions().count()
+    }
+
+    pub fn functions(&self) -> ModuleIter<'_, Function> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstFunction(self.as_raw()), LLVMGetNextFunction, Function::from_raw)
+        }
+    }
+
+    pub fn get_data_layout(&self) -> String {
+        unsafe {
+            CStr::from_ptr(LLVMGetDataLayoutStr(self.as_raw())).to_str().expect("to_str").to_string()
+        }
+    }
+
+    pub fn get_identifier(&self) -> String {
+        unsafe {
+            let mut len = 0;
+            let identifier = LLVMGetModuleIdentifier(self.as_raw(), &mut len);
+            string_from_raw_parts(identifier, len)
+        }
+    }
+
+    pub fn get_inline_asm(&self) -> String {
+        unsafe {
+            let mut len = 0;
+            let asm = LLVMGetModuleInlineAsm(self.as_raw(), &mut len);
+            string_from_raw_parts(asm, len)
+        }
+    }
+
+    pub fn get_module_flag(&self, key: &str) -> Option<Metadata> {
+        unsafe {
+            let metadata = LLVMGetModuleFlag(self.as_raw(), key.as_ptr() as *const c_char, key.len());
+            if metadata.is_null() {
+                None
+            }
+            else {
+                Some(Metadata::from_raw(metadata))
+            }
+        }
+    }
+
     pub fn get_named_function(&self, name: &str) -> Option<Function> {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -80,9 +240,154 @@ impl Module {
         }
     }
 
+    pub fn get_named_global(&self, name: &str) -> Option<GlobalVariable> {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            let value = LLVMGetNamedGlobal(self.as_raw(), cstring.as_ptr());
+            if value.is_null() {
+                None
+            }
+            else {
+                Some(GlobalVariable::from_raw(value))
+            }
+        }
+    }
+
+    pub fn get_named_metadata_operands(&self, name: &str) -> Vec<Metadata> {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            let count = LLVMGetNamedMetadataNumOperands(self.as_raw(), cstring.as_ptr()) as usize;
+            let mut values = vec![ptr::null_mut(); count];
+            LLVMGetNamedMetadataOperands(self.as_raw(), cstring.as_ptr(), values.as_mut_ptr());
+            values.into_iter()
+                .map(|value| Metadata::from_raw(LLVMValueAsMetadata(value)))
+                .collect()
+        }
+    }
+
+    pub fn get_or_insert_comdat(&self, name: &str) -> Comdat {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            Comdat::from_raw(LLVMGetOrInsertComdat(self.as_raw(), cstring.as_ptr()))
+        }
+    }
+
+    pub fn get_source_filename(&self) -> String {
+        unsafe {
+            let mut len = 0;
+            let filename = LLVMGetSourceFileName(self.as_raw(), &mut len);
+            string_from_raw_parts(filename, len)
+        }
+    }
+
+    pub fn get_target(&self) -> String {
+        unsafe {
+            CStr::from_ptr(LLVMGetTarget(self.as_raw())).to_str().expect("to_str").to_string()
+        }
+    }
+
+    pub fn get_type_by_name(&self, name: &str) -> Option<Type> {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            let typ = LLVMGetTypeByName2(LLVMGetModuleContext(self.as_raw()), cstring.as_ptr());
+            if typ.is_null() {
+                None
+            }
+            else {
+                Some(Type::from_raw(typ))
+            }
+        }
+    }
+
+    pub fn global_count(&self) -> usize {
+        self.globals().count()
+    }
+
+    pub fn globals(&self) -> ModuleIter<'_, GlobalVariable> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstGlobal(self.as_raw()), LLVMGetNextGlobal, GlobalVariable::from_raw)
+        }
+    }
+
+    pub fn ifunc_count(&self) -> usize {
+        self.ifuncs().count()
+    }
+
+    pub fn ifuncs(&self) -> ModuleIter<'_, GlobalIFunc> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstGlobalIFunc(self.as_raw()), LLVMGetNextGlobalIFunc, GlobalIFunc::from_raw)
+        }
+    }
+
+    // NOTE: the linker reports errors through the context diagnostic handler, which exits the
+    // process by default, so a handler collecting the errors is installed while linking.
+    pub fn link_in(&self, other: Module) -> Result<()> {
+        unsafe {
+            let context = LLVMGetModuleContext(self.as_raw());
+            let mut link_diagnostics = LinkDiagnostics {
+                errors: vec![],
+                previous_context: LLVMContextGetDiagnosticContext(context),
+                previous_handler: LLVMContextGetDiagnosticHandler(context),
+            };
+            let diagnostics_ptr = &mut link_diagnostics as *mut LinkDiagnostics as *mut c_void;
+            LLVMContextSetDiagnosticHandler(context, Some(link_diagnostic_handler), diagnostics_ptr);
+            let result = LLVMLinkModules2(self.as_raw(), other.as_raw());
+            LLVMContextSetDiagnosticHandler(context, link_diagnostics.previous_handler, link_diagnostics.previous_context);
+            if result != 0 {
+                return Err(Error::Link(link_diagnostics.errors.join("\n")));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn print_to_file(&self, filename: &str) -> Result<()> {
+        let mut error = ptr::null_mut();
+        let filename = CString::new(filename).expect("cstring");
+        unsafe {
+            if LLVMPrintModuleToFile(self.as_raw(), filename.as_ptr(), &mut error) != 0 {
+                let cstr = CStr::from_ptr(error);
+                let print_error = cstr.to_str().expect("error cstr").to_string();
+                LLVMDisposeMessage(error);
+                Err(Error::Print(print_error))
+            }
+            else {
+                Ok(())
+            }
+        }
+    }
+
+    pub fn print_to_string(&self) -> String {
+        unsafe {
+            let cstring = LLVMPrintModuleToString(self.as_raw());
+            let result = CStr::from_ptr(cstring).to_str().expect("to_str").to_string();
+            LLVMDisposeMessage(cstring);
+            result
+        }
+    }
+
     pub fn set_data_layout(&self, data_layout: TargetData) {
         unsafe {
-            LLVMSetDataLayout(self.as_raw(), data_layout.as_raw() as *const _);
+            let layout = LLVMCopyStringRepOfTargetData(data_layout.as_raw());
+            LLVMSetDataLayout(self.as_raw(), layout);
+            LLVMDisposeMessage(layout);
+        }
+    }
+
+    pub fn set_identifier(&self, identifier: &str) {
+        unsafe {
+            LLVMSetModuleIdentifier(self.as_raw(), identifier.as_ptr() as *const c_char, identifier.len());
+        }
+    }
+
+    pub fn set_inline_asm(&self, asm: &str) {
+        unsafe {
+            LLVMSetModuleInlineAsm2(self.as_raw(), asm.as_ptr() as *const c_char, asm.len());
+        }
+    }
+
+    pub fn set_source_filename(&self, filename: &str) {
+        unsafe {
+            LLVMSetSourceFileName(self.as_raw(), filename.as_ptr() as *const c_char, filename.len());
         }
     }
 
@@ -93,6 +398,80 @@ impl Module {
     }
 }
 
+struct LinkDiagnostics {
+    errors: Vec<String>,
+    previous_context: *mut c_void,
+    previous_handler: LLVMDiagnosticHandler,
+}
+
+// Collect the errors and forward the other diagnostics to the previous handler.
+extern "C" fn link_diagnostic_handler(info: LLVMDiagnosticInfoRef, diagnostics: *mut c_void) {
+    unsafe {
+        let diagnostics = &mut *(diagnostics as *mut LinkDiagnostics);
+        match LLVMGetDiagInfoSeverity(info) {
+            LLVMDiagnosticSeverity::LLVMDSError => {
+                let message = LLVMGetDiagInfoDescription(info);
+                diagnostics.errors.push(CStr::from_ptr(message).to_string_lossy().into_owned());
+                LLVMDisposeMessage(message);
+            },
+            _ => {
+                if let Some(handler) = diagnostics.previous_handler {
+                    handler(info, diagnostics.previous_context);
+                }
+            },
+        }
+    }
+}
+
+// NOTE: like the other modules, the clone is not disposed when dropped: it is owned by its context
+// and freed with it, so cloning a module of the global context leaks it.
+impl Clone for Module {
+    fn clone(&self) -> Self {
+        unsafe {
+            Self(LLVMCloneModule(self.as_raw()))
+        }
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.print_to_string())
+    }
+}
+
+pub struct ModuleIter<'a, T> {
+    current: LLVMValueRef,
+    next: unsafe extern "C" fn(LLVMValueRef) -> LLVMValueRef,
+    wrap: unsafe fn(LLVMValueRef) -> T,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a, T> ModuleIter<'a, T> {
+    pub(crate) unsafe fn new(first: LLVMValueRef, next: unsafe extern "C" fn(LLVMValueRef) -> LLVMValueRef, wrap: unsafe fn(LLVMValueRef) -> T) -> Self {
+        Self {
+            current: first,
+            next,
+            wrap,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ModuleIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        unsafe {
+            let value = self.current;
+            self.current = (self.next)(value);
+            Some((self.wrap)(value))
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Function(LLVMValueRef);
 
@@ -119,6 +498,12 @@ impl Function {
         self.0
     }
 
+    pub fn basic_blocks(&self) -> ModuleIter<'_, BasicBlock> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstBasicBlock(self.as_raw()), LLVMGetNextBasicBlock, BasicBlock::from_raw)
+        }
+    }
+
     pub fn delete(&self) {
         unsafe { LLVMDeleteFunction(self.as_raw()); }
     }
@@ -132,21 +517,76 @@ impl Function {
         unsafe { BasicBlock::from_raw(LLVMGetEntryBasicBlock(self.as_raw())) }
     }
 
+    pub fn function_type(&self) -> Type {
+        unsafe {
+            Type::from_raw(LLVMGlobalGetValueType(self.as_raw()))
+        }
+    }
+
+    pub fn get_gc(&self) -> Option<String> {
+        unsafe {
+            let gc = LLVMGetGC(self.as_raw());
+            if gc.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(gc).to_str().expect("to_str").to_string())
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        unsafe {
+            let mut len = 0;
+            let name = LLVMGetValueName2(self.as_raw(), &mut len);
+            string_from_raw_parts(name, len)
+        }
+    }
+
     pub fn get_param(&self, index: usize) -> Value {
         unsafe {
             Value::from_raw(LLVMGetParam(self.as_raw(), index as u32))
         }
     }
 
+    pub fn last_basic_block(&self) -> Option<BasicBlock> {
+        unsafe {
+            let basic_block = LLVMGetLastBasicBlock(self.as_raw());
+            if basic_block.is_null() {
+                return None;
+            }
+            Some(BasicBlock::from_raw(basic_block))
+        }
+    }
+
     pub fn param_count(&self) -> usize {
         unsafe { LLVMCountParams(self.as_raw()) as usize }
     }
 
-    pub fn size(&self) -> usize {
-        unsafe { LLVMCountBasicBlocks(self.as_raw()) as usize }
+    pub fn params(&self) -> ModuleIter<'_, Value> {
+        unsafe {
+            ModuleIter::new(LLVMGetFirstParam(self.as_raw()), LLVMGetNextParam, Value::from_raw)
+        }
     }
 
-    pub fn verify(&self, action: VerifierFailureAction) -> bool {
-        unsafe { LLVMVerifyFunction(self.as_raw(), action.as_raw()) != 0 }
+    pub fn return_type(&self) -> Type {
+        unsafe {
+            Type::from_raw(LLVMGetReturnType(LLVMGlobalGetValueType(self.as_raw())))
+        }
+    }
+
+    pub fn set_gc(&self, name: &str) {
+        let cstring = CString::new(name).expect("cstring");
+        unsafe {
+            LLVMSetGC(self.as_raw(), cstring.as_ptr());
+        }
+    }
+
+    pub fn set_name(&self, name: &str) {
+        unsafe {
+            LLVMSetValueName2(self.as_raw(), name.as_ptr() as *const c_char, name.len());
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        unsafe { LLVMCountBasicBlocks(self.as_raw()) as usize }
     }
 }
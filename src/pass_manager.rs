@@ -1,4 +1,7 @@
+use std::ffi::{CStr, CString};
+
 use Module;
+use error::{Error, Result};
 use module::Function;
 use ffi::{
     LLVMAddCFGSimplificationPass,
@@ -7,13 +10,27 @@ use ffi::{
     LLVMAddInstructionCombiningPass,
     LLVMAddReassociatePass,
     LLVMCreateFunctionPassManagerForModule,
+    LLVMCreatePassBuilderOptions,
     LLVMCreatePassManager,
+    LLVMDisposeErrorMessage,
+    LLVMDisposePassBuilderOptions,
     LLVMDisposePassManager,
+    LLVMGetErrorMessage,
+    LLVMPassBuilderOptionsRef,
+    LLVMPassBuilderOptionsSetDebugLogging,
+    LLVMPassBuilderOptionsSetLoopInterleaving,
+    LLVMPassBuilderOptionsSetLoopUnrolling,
+    LLVMPassBuilderOptionsSetLoopVectorization,
+    LLVMPassBuilderOptionsSetMergeFunctions,
+    LLVMPassBuilderOptionsSetSLPVectorization,
+    LLVMPassBuilderOptionsSetVerifyEach,
     LLVMPassManagerRef,
     LLVMAddPromoteMemoryToRegisterPass,
     LLVMRunFunctionPassManager,
     LLVMRunPassManager,
+    LLVMRunPasses,
 };
+use target::TargetMachine;
 
 pub struct FunctionPassManager(LLVMPassManagerRef);
 
@@ -106,3 +123,90 @@ impl Drop for ModulePassManager {
         }
     }
 }
+
+pub struct PassBuilderOptions(LLVMPassBuilderOptionsRef);
+
+impl PassBuilderOptions {
+    pub fn new() -> Self {
+        unsafe {
+            PassBuilderOptions(LLVMCreatePassBuilderOptions())
+        }
+    }
+
+    pub fn as_raw(&self) -> LLVMPassBuilderOptionsRef {
+        self.0
+    }
+
+    pub fn set_debug_logging(&self, debug_logging: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetDebugLogging(self.as_raw(), debug_logging as i32);
+        }
+    }
+
+    pub fn set_loop_interleaving(&self, loop_interleaving: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetLoopInterleaving(self.as_raw(), loop_interleaving as i32);
+        }
+    }
+
+    pub fn set_loop_unrolling(&self, loop_unrolling: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetLoopUnrolling(self.as_raw(), loop_unrolling as i32);
+        }
+    }
+
+    pub fn set_loop_vectorization(&self, loop_vectorization: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetLoopVectorization(self.as_raw(), loop_vectorization as i32);
+        }
+    }
+
+    pub fn set_merge_functions(&self, merge_functions: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetMergeFunctions(self.as_raw(), merge_functions as i32);
+        }
+    }
+
+    pub fn set_slp_vectorization(&self, slp_vectorization: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetSLPVectorization(self.as_raw(), slp_vectorization as i32);
+        }
+    }
+
+    pub fn set_verify_each(&self, verify_each: bool) {
+        unsafe {
+            LLVMPassBuilderOptionsSetVerifyEach(self.as_raw(), verify_each as i32);
+        }
+    }
+}
+
+impl Default for PassBuilderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PassBuilderOptions {
+    fn drop(&mut self) {
+        unsafe {
+            LLVMDisposePassBuilderOptions(self.as_raw());
+        }
+    }
+}
+
+impl Module {
+    // The pipeline uses the syntax of opt -passes, e.g. "default<O2>" or "function(mem2reg,instcombine)".
+    pub fn run_passes(&self, pipeline: &str, target_machine: &TargetMachine, options: PassBuilderOptions) -> Result<()> {
+        let cstring = CString::new(pipeline).expect("cstring");
+        unsafe {
+            let error = LLVMRunPasses(self.as_raw(), cstring.as_ptr(), target_machine.as_raw(), options.as_raw());
+            if !error.is_null() {
+                let message = LLVMGetErrorMessage(error);
+                let run_error = CStr::from_ptr(message).to_string_lossy().into_owned();
+                LLVMDisposeErrorMessage(message);
+                return Err(Error::RunPasses(run_error));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * SSA construction for mutable variables without allocas, following "Simple and Efficient
+ * Construction of Static Single Assignment Form" by Braun et al.
+ *
+ * A block is sealed when all its predecessors are known: reading a variable in an unsealed block
+ * creates a phi whose operands are only added when the block is sealed. The entry block is always
+ * sealed and SsaBuilder::finalize() seals the remaining blocks, so sealing explicitly is only an
+ * optimization.
+ */
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ffi::CString;
+use std::rc::Rc;
+
+use {BasicBlock, Builder, Value};
+use ffi::{
+    LLVMAddIncoming,
+    LLVMBasicBlockRef,
+    LLVMBuildPhi,
+    LLVMCountIncoming,
+    LLVMCreateBuilderInContext,
+    LLVMDisposeBuilder,
+    LLVMGetBasicBlockParent,
+    LLVMGetEntryBasicBlock,
+    LLVMGetFirstInstruction,
+    LLVMGetFirstUse,
+    LLVMGetIncomingValue,
+    LLVMGetNextUse,
+    LLVMGetTypeContext,
+    LLVMGetUndef,
+    LLVMGetUser,
+    LLVMInstructionEraseFromParent,
+    LLVMPositionBuilder,
+    LLVMReplaceAllUsesWith,
+    LLVMTypeOf,
+    LLVMValueRef,
+};
+use types::Type;
+
+struct VariableInfo {
+    name: String,
+    typ: Type,
+}
+
+#[derive(Default)]
+struct State {
+    all_sealed: bool,
+    definitions: HashMap<(usize, LLVMBasicBlockRef), LLVMValueRef>,
+    // Phis returned by Variable::read(), which must stay valid even if they turn out to be trivial.
+    escaped_phis: HashSet<LLVMValueRef>,
+    incomplete_phis: HashMap<LLVMBasicBlockRef, Vec<(usize, LLVMValueRef)>>,
+    // Phis created by this builder which were not removed.
+    phis: HashSet<LLVMValueRef>,
+    sealed_blocks: HashSet<LLVMBasicBlockRef>,
+    variables: Vec<VariableInfo>,
+}
+
+impl State {
+    fn add_phi_operands(&mut self, variable: usize, phi: LLVMValueRef, block: LLVMBasicBlockRef) -> LLVMValueRef {
+        let basic_block = unsafe { BasicBlock::from_raw(block) };
+        for predecessor in basic_block.predecessors() {
+            let mut value = self.read(variable, predecessor.as_raw());
+            let mut predecessor_block = predecessor.as_raw();
+            // A predecessor can branch multiple times to the same block (e.g. a switch) and the phi
+            // needs an entry for each edge.
+            let edge_count = predecessor.successors().iter()
+                .filter(|successor| **successor == basic_block)
+                .count();
+            for _ in 0..edge_count {
+                unsafe {
+                    LLVMAddIncoming(phi, &mut value, &mut predecessor_block, 1);
+                }
+            }
+        }
+        self.try_remove_trivial_phi(phi)
+    }
+
+    fn is_sealed(&self, block: LLVMBasicBlockRef) -> bool {
+        self.all_sealed || self.sealed_blocks.contains(&block) ||
+            unsafe { LLVMGetEntryBasicBlock(LLVMGetBasicBlockParent(block)) == block }
+    }
+
+    fn new_phi(&mut self, variable: usize, block: LLVMBasicBlockRef) -> LLVMValueRef {
+        let info = &self.variables[variable];
+        let cstring = CString::new(info.name.as_str()).expect("cstring");
+        unsafe {
+            let builder = LLVMCreateBuilderInContext(LLVMGetTypeContext(info.typ.as_raw()));
+            LLVMPositionBuilder(builder, block, LLVMGetFirstInstruction(block));
+            let phi = LLVMBuildPhi(builder, info.typ.as_raw(), cstring.as_ptr());
+            LLVMDisposeBuilder(builder);
+            self.phis.insert(phi);
+            phi
+        }
+    }
+
+    fn read(&mut self, variable: usize, block: LLVMBasicBlockRef) -> LLVMValueRef {
+        match self.definitions.get(&(variable, block)) {
+            Some(&value) => value,
+            None => self.read_recursive(variable, block),
+        }
+    }
+
+    fn read_recursive(&mut self, variable: usize, block: LLVMBasicBlockRef) -> LLVMValueRef {
+        let value =
+            if !self.is_sealed(block) {
+                let phi = self.new_phi(variable, block);
+                self.incomplete_phis.entry(block).or_default().push((variable, phi));
+                phi
+            }
+            else {
+                let predecessors = unsafe { BasicBlock::from_raw(block) }.predecessors();
+                match predecessors.len() {
+                    0 => unsafe { LLVMGetUndef(self.variables[variable].typ.as_raw()) },
+                    1 => self.read(variable, predecessors[0].as_raw()),
+                    _ => {
+                        // Write the phi before adding its operands to break cycles in loops.
+                        let phi = self.new_phi(variable, block);
+                        self.write(variable, block, phi);
+                        self.add_phi_operands(variable, phi, block)
+                    },
+                }
+            };
+        self.write(variable, block, value);
+        value
+    }
+
+    fn seal(&mut self, block: LLVMBasicBlockRef) {
+        if let Some(phis) = self.incomplete_phis.remove(&block) {
+            for (variable, phi) in phis {
+                self.add_phi_operands(variable, phi, block);
+            }
+        }
+        self.sealed_blocks.insert(block);
+    }
+
+    fn try_remove_trivial_phi(&mut self, phi: LLVMValueRef) -> LLVMValueRef {
+        let mut same = None;
+        unsafe {
+            for index in 0..LLVMCountIncoming(phi) {
+                let operand = LLVMGetIncomingValue(phi, index);
+                if Some(operand) == same || operand == phi {
+                    continue;
+                }
+                if same.is_some() {
+                    // The phi merges at least two values: not trivial.
+                    return phi;
+                }
+                same = Some(operand);
+            }
+            let same = same.unwrap_or_else(|| LLVMGetUndef(LLVMTypeOf(phi)));
+
+            let mut users = vec![];
+            let mut usage = LLVMGetFirstUse(phi);
+            while !usage.is_null() {
+                let user = LLVMGetUser(usage);
+                if user != phi && self.phis.contains(&user) && !users.contains(&user) {
+                    users.push(user);
+                }
+                usage = LLVMGetNextUse(usage);
+            }
+
+            LLVMReplaceAllUsesWith(phi, same);
+            for value in self.definitions.values_mut() {
+                if *value == phi {
+                    *value = same;
+                }
+            }
+            self.phis.remove(&phi);
+            // The replacement also applies to the operands of the phi, so an escaped phi is left with
+            // the same value on every edge, which later optimizations remove.
+            if !self.escaped_phis.contains(&phi) {
+                LLVMInstructionEraseFromParent(phi);
+            }
+
+            // Removing this phi might make the phis using it trivial.
+            for user in users {
+                if self.phis.contains(&user) {
+                    self.try_remove_trivial_phi(user);
+                }
+            }
+            same
+        }
+    }
+
+    fn write(&mut self, variable: usize, block: LLVMBasicBlockRef, value: LLVMValueRef) {
+        self.definitions.insert((variable, block), value);
+    }
+}
+
+pub struct SsaBuilder {
+    state: Rc<RefCell<State>>,
+}
+
+impl SsaBuilder {
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(State::default())),
+        }
+    }
+
+    // NOTE: this must be called once the control flow graph of the function is complete, otherwise
+    // the function might contain phis without operands.
+    pub fn finalize(self) {
+        let mut state = self.state.borrow_mut();
+        state.all_sealed = true;
+        let blocks: Vec<_> = state.incomplete_phis.keys().cloned().collect();
+        for block in blocks {
+            state.seal(block);
+        }
+    }
+
+    pub fn new_variable(&self, typ: Type, name: &str) -> Variable {
+        let mut state = self.state.borrow_mut();
+        state.variables.push(VariableInfo {
+            name: name.to_string(),
+            typ,
+        });
+        Variable {
+            id: state.variables.len() - 1,
+            state: self.state.clone(),
+        }
+    }
+
+    pub fn seal_block(&self, block: &BasicBlock) {
+        self.state.borrow_mut().seal(block.as_raw());
+    }
+}
+
+impl Default for SsaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct Variable {
+    id: usize,
+    state: Rc<RefCell<State>>,
+}
+
+impl Variable {
+    pub fn get(&self, builder: &Builder) -> Value {
+        self.read(&builder.get_insert_block().expect("position the builder before reading a variable"))
+    }
+
+    // NOTE: the value stays valid after sealing the blocks: a phi returned here is not erased when it
+    // turns out to be trivial, since it might only be used after finalize(). Such a phi is left with
+    // the same value on every edge, and without uses if the value is never used, so the output can
+    // contain dead phis which a later pass (e.g. instcombine) removes.
+    pub fn read(&self, block: &BasicBlock) -> Value {
+        let mut state = self.state.borrow_mut();
+        let value = state.read(self.id, block.as_raw());
+        if state.phis.contains(&value) {
+            state.escaped_phis.insert(value);
+        }
+        unsafe {
+            Value::from_raw(value)
+        }
+    }
+
+    pub fn set(&self, builder: &Builder, value: &Value) {
+        self.write(&builder.get_insert_block().expect("position the builder before writing a variable"), value);
+    }
+
+    pub fn write(&self, block: &BasicBlock, value: &Value) {
+        debug_assert_eq!(value.get_type(), self.state.borrow().variables[self.id].typ, "the value type should match the variable type");
+        self.state.borrow_mut().write(self.id, block.as_raw(), value.as_raw());
+    }
+}
@@ -1,4 +1,5 @@
 use std::ffi::{CStr, CString};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 use std::ptr;
@@ -10,6 +11,7 @@ use ffi::{
     LLVMCodeGenFileType,
     LLVMCodeGenOptLevel,
     LLVMCodeModel,
+    LLVMCreateMessage,
     LLVMCreateTargetDataLayout,
     LLVMCreateTargetMachine,
     LLVMDisposeMessage,
@@ -24,6 +26,7 @@ use ffi::{
     LLVM_InitializeAllAsmPrinters,
     LLVM_InitializeNativeAsmPrinter,
     LLVM_InitializeNativeTarget,
+    LLVMNormalizeTargetTriple,
     LLVMRelocMode,
     LLVMTargetRef,
     LLVMTargetMachineRef,
@@ -211,13 +214,44 @@ impl Target {
 pub struct TargetTriple(*const c_char);
 
 impl TargetTriple {
+    pub fn new(triple: &str) -> Self {
+        let cstring = CString::new(triple).expect("cstring");
+        unsafe {
+            Self(LLVMCreateMessage(cstring.as_ptr()))
+        }
+    }
+
     pub fn as_raw(&self) -> *const c_char {
         self.0
     }
 
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            CStr::from_ptr(self.as_raw()).to_str().expect("to_str")
+        }
+    }
+
     pub unsafe fn from_raw(triple: *const c_char) -> TargetTriple {
         Self(triple)
     }
+
+    pub fn normalize(&self) -> TargetTriple {
+        unsafe {
+            Self(LLVMNormalizeTargetTriple(self.as_raw()))
+        }
+    }
+}
+
+impl Debug for TargetTriple {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.as_str())
+    }
+}
+
+impl Display for TargetTriple {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.as_str())
+    }
 }
 
 impl Drop for TargetTriple {
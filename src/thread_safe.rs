@@ -0,0 +1,84 @@
+use std::sync::{Arc, Mutex};
+
+use {Context, ExecutionEngine, Module};
+
+// A context which is only accessed while it is locked.
+struct LockedContext(Context);
+
+unsafe impl Send for LockedContext {}
+
+#[derive(Clone)]
+pub struct ThreadSafeContext {
+    context: Arc<Mutex<LockedContext>>,
+}
+
+impl ThreadSafeContext {
+    pub fn new() -> Self {
+        Self::from_context(Context::new())
+    }
+
+    pub fn from_context(context: Context) -> Self {
+        Self {
+            context: Arc::new(Mutex::new(LockedContext(context))),
+        }
+    }
+
+    pub fn new_module(&self, name: &str) -> ThreadSafeModule {
+        let module = self.context.lock().expect("context lock").0.new_module(name);
+        ThreadSafeModule {
+            context: self.clone(),
+            module,
+        }
+    }
+
+    // NOTE: the modules, builders, types and values created in the closure must not outlive it,
+    // since another thread can use the context once the closure returns.
+    pub fn with_context<F, T>(&self, f: F) -> T
+    where F: FnOnce(&Context) -> T,
+          T: Send,
+    {
+        let context = self.context.lock().expect("context lock");
+        f(&context.0)
+    }
+}
+
+impl Default for ThreadSafeContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ThreadSafeModule {
+    context: ThreadSafeContext,
+    module: Module,
+}
+
+// The module is only accessed while its context is locked.
+unsafe impl Send for ThreadSafeModule {}
+
+impl ThreadSafeModule {
+    pub fn context(&self) -> &ThreadSafeContext {
+        &self.context
+    }
+
+    // NOTE: the engine takes ownership of the module. The engine and the code it generated are freed
+    // when the closure returns, so the function addresses must not outlive it.
+    pub fn with_execution_engine<F, T>(self, f: F) -> Result<T, String>
+    where F: FnOnce(&ExecutionEngine, &Context) -> T,
+          T: Send,
+    {
+        let context = self.context.context.lock().expect("context lock");
+        let engine = ExecutionEngine::new_for_module(&self.module)?;
+        Ok(f(&engine, &context.0))
+    }
+
+    // NOTE: the builders, types and values created in the closure must not outlive it, since
+    // another thread can use the context once the closure returns. Do not give the module to an
+    // execution engine, which would take ownership of it: use with_execution_engine() instead.
+    pub fn with_module<F, T>(&self, f: F) -> T
+    where F: FnOnce(&Module, &Context) -> T,
+          T: Send,
+    {
+        self.context.with_context(|context| f(&self.module, context))
+    }
+}
@@ -0,0 +1,288 @@
+use std::ffi::CString;
+use std::os::raw::c_uint;
+
+use {BasicBlock, Builder, Instruction, IntPredicate, RealPredicate};
+use ffi::LLVMBuildCall2;
+use types::Type;
+use value::Value;
+use value::typed::{FloatValue, FunctionValue, IntValue, PointerValue, VectorValue};
+
+type BinaryOp = fn(&Builder, &Value, &Value, &str) -> Value;
+type CastOp = fn(&Builder, &Value, Type, &str) -> Value;
+type UnaryOp = fn(&Builder, &Value, &str) -> Value;
+
+impl Builder {
+    pub fn typed(&self) -> TypedBuilder<'_> {
+        TypedBuilder {
+            builder: self,
+        }
+    }
+}
+
+// NOTE: the methods of this builder only accept operands of the right kind of type, so that mixing
+// an integer with a float does not compile. The sizes of the operands are still checked at runtime.
+pub struct TypedBuilder<'a> {
+    builder: &'a Builder,
+}
+
+impl<'a> TypedBuilder<'a> {
+    pub fn add(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::add, left, right, name)
+    }
+
+    pub fn alloca(&self, typ: Type, name: &str) -> PointerValue {
+        unsafe {
+            PointerValue::from_raw(self.builder.alloca(typ, name).as_raw())
+        }
+    }
+
+    pub fn and(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::and, left, right, name)
+    }
+
+    pub fn ashr(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::ashr, left, right, name)
+    }
+
+    pub fn call(&self, function: &FunctionValue, args: &[Value], name: &str) -> Value {
+        assert_eq!(function.as_function().param_count(), args.len(), "wrong number of argument for function call");
+        let cstring = CString::new(name).expect("cstring");
+        let mut args: Vec<_> = args.iter().map(Value::as_raw).collect();
+        unsafe {
+            Value::from_raw(LLVMBuildCall2(self.builder.as_raw(), function.function_type().as_raw(), function.as_raw(), args.as_mut_ptr(),
+                args.len() as c_uint, cstring.as_ptr()))
+        }
+    }
+
+    pub fn cond_br(&self, condition: &IntValue, then: &BasicBlock, else_block: &BasicBlock) -> Instruction {
+        debug_assert_eq!(condition.bit_width(), 1, "the condition of a branch should be an i1");
+        unsafe {
+            Instruction::from_raw(self.builder.cond_br(&condition.as_value(), then, else_block).as_raw())
+        }
+    }
+
+    pub fn extract_element(&self, vector: &VectorValue, index: &IntValue, name: &str) -> Value {
+        self.builder.extract_element(&vector.as_value(), &index.as_value(), name)
+    }
+
+    pub fn fadd(&self, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        self.float_binary(Builder::fadd, left, right, name)
+    }
+
+    pub fn fcmp(&self, predicate: RealPredicate, left: &FloatValue, right: &FloatValue, name: &str) -> IntValue {
+        debug_assert_eq!(left.get_type(), right.get_type(), "operands of fcmp should have the same type");
+        unsafe {
+            IntValue::from_raw(self.builder.fcmp(predicate, &left.as_value(), &right.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn fdiv(&self, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        self.float_binary(Builder::fdiv, left, right, name)
+    }
+
+    pub fn floating_point_extend(&self, value: &FloatValue, dest_type: Type, name: &str) -> FloatValue {
+        debug_assert!(dest_type.is_floating_point(), "the destination type should be a floating point type");
+        unsafe {
+            FloatValue::from_raw(self.cast(Builder::floating_point_extend, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn floating_point_to_signed_int(&self, value: &FloatValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::floating_point_to_signed_int, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn floating_point_to_unsigned_int(&self, value: &FloatValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::floating_point_to_unsigned_int, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn floating_point_trunc(&self, value: &FloatValue, dest_type: Type, name: &str) -> FloatValue {
+        debug_assert!(dest_type.is_floating_point(), "the destination type should be a floating point type");
+        unsafe {
+            FloatValue::from_raw(self.cast(Builder::floating_point_trunc, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn fmul(&self, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        self.float_binary(Builder::fmul, left, right, name)
+    }
+
+    pub fn fneg(&self, value: &FloatValue, name: &str) -> FloatValue {
+        unsafe {
+            FloatValue::from_raw(self.unary(Builder::fneg, &value.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn frem(&self, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        self.float_binary(Builder::frem, left, right, name)
+    }
+
+    pub fn fsub(&self, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        self.float_binary(Builder::fsub, left, right, name)
+    }
+
+    pub fn gep(&self, typ: &Type, pointer: &PointerValue, indices: &[IntValue], name: &str) -> PointerValue {
+        let indices: Vec<_> = indices.iter().map(IntValue::as_value).collect();
+        unsafe {
+            PointerValue::from_raw(self.builder.gep(typ, &pointer.as_value(), &indices, name).as_raw())
+        }
+    }
+
+    pub fn icmp(&self, predicate: IntPredicate, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        debug_assert_eq!(left.get_type(), right.get_type(), "operands of icmp should have the same type");
+        unsafe {
+            IntValue::from_raw(self.builder.icmp(predicate, &left.as_value(), &right.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn insert_element(&self, vector: &VectorValue, element: &Value, index: &IntValue, name: &str) -> VectorValue {
+        debug_assert_eq!(vector.get_type().element_type(), element.get_type(), "the element type should match the vector element type");
+        unsafe {
+            VectorValue::from_raw(self.builder.insert_element(&vector.as_value(), element, &index.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn int_to_ptr(&self, value: &IntValue, dest_type: Type, name: &str) -> PointerValue {
+        debug_assert!(dest_type.is_pointer(), "the destination type should be a pointer type");
+        unsafe {
+            PointerValue::from_raw(self.cast(Builder::int_to_ptr, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn load(&self, typ: Type, pointer: &PointerValue, name: &str) -> Value {
+        self.builder.load(typ, &pointer.as_value(), name)
+    }
+
+    pub fn lshr(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::lshr, left, right, name)
+    }
+
+    pub fn mul(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::mul, left, right, name)
+    }
+
+    pub fn neg(&self, value: &IntValue, name: &str) -> IntValue {
+        unsafe {
+            IntValue::from_raw(self.unary(Builder::neg, &value.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn not(&self, value: &IntValue, name: &str) -> IntValue {
+        unsafe {
+            IntValue::from_raw(self.unary(Builder::not, &value.as_value(), name).as_raw())
+        }
+    }
+
+    pub fn or(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::or, left, right, name)
+    }
+
+    pub fn ptr_to_int(&self, value: &PointerValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::ptr_to_int, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn sdiv(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::div, left, right, name)
+    }
+
+    pub fn shl(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::shl, left, right, name)
+    }
+
+    pub fn sign_extend(&self, value: &IntValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::sign_extend, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn signed_int_to_floating_point(&self, value: &IntValue, dest_type: Type, name: &str) -> FloatValue {
+        debug_assert!(dest_type.is_floating_point(), "the destination type should be a floating point type");
+        unsafe {
+            FloatValue::from_raw(self.cast(Builder::signed_int_to_floating_point, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn srem(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::srem, left, right, name)
+    }
+
+    pub fn store(&self, value: &Value, pointer: &PointerValue) -> Instruction {
+        unsafe {
+            Instruction::from_raw(self.builder.store(value, &pointer.as_value()).as_raw())
+        }
+    }
+
+    pub fn struct_gep(&self, typ: &Type, pointer: &PointerValue, index: usize, name: &str) -> PointerValue {
+        unsafe {
+            PointerValue::from_raw(self.builder.struct_gep(typ, &pointer.as_value(), index, name).as_raw())
+        }
+    }
+
+    pub fn sub(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::sub, left, right, name)
+    }
+
+    pub fn trunc(&self, value: &IntValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::trunc, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn udiv(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::udiv, left, right, name)
+    }
+
+    pub fn unsigned_int_to_floating_point(&self, value: &IntValue, dest_type: Type, name: &str) -> FloatValue {
+        debug_assert!(dest_type.is_floating_point(), "the destination type should be a floating point type");
+        unsafe {
+            FloatValue::from_raw(self.cast(Builder::unsigned_int_to_floating_point, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    pub fn urem(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::urem, left, right, name)
+    }
+
+    pub fn xor(&self, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        self.int_binary(Builder::xor, left, right, name)
+    }
+
+    pub fn zero_extend(&self, value: &IntValue, dest_type: Type, name: &str) -> IntValue {
+        debug_assert!(dest_type.is_integer(), "the destination type should be an integer type");
+        unsafe {
+            IntValue::from_raw(self.cast(Builder::zero_extend, &value.as_value(), dest_type, name).as_raw())
+        }
+    }
+
+    fn cast(&self, build: CastOp, value: &Value, dest_type: Type, name: &str) -> Value {
+        build(self.builder, value, dest_type, name)
+    }
+
+    fn float_binary(&self, build: BinaryOp, left: &FloatValue, right: &FloatValue, name: &str) -> FloatValue {
+        debug_assert_eq!(left.get_type(), right.get_type(), "operands of a floating point instruction should have the same type");
+        unsafe {
+            FloatValue::from_raw(build(self.builder, &left.as_value(), &right.as_value(), name).as_raw())
+        }
+    }
+
+    fn int_binary(&self, build: BinaryOp, left: &IntValue, right: &IntValue, name: &str) -> IntValue {
+        debug_assert_eq!(left.get_type(), right.get_type(), "operands of an integer instruction should have the same type");
+        unsafe {
+            IntValue::from_raw(build(self.builder, &left.as_value(), &right.as_value(), name).as_raw())
+        }
+    }
+
+    fn unary(&self, build: UnaryOp, value: &Value, name: &str) -> Value {
+        build(self.builder, value, name)
+    }
+}
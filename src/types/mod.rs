@@ -14,8 +14,10 @@ use ffi::{
     LLVMDumpType,
     LLVMGetElementType,
     LLVMGetNumContainedTypes,
+    LLVMGetTypeKind,
     LLVMPrintTypeToString,
     LLVMSizeOf,
+    LLVMTypeKind,
     LLVMTypeRef,
     LLVMVoidType,
 };
@@ -55,6 +57,34 @@ impl Type {
         Type(typ)
     }
 
+    pub fn is_floating_point(&self) -> bool {
+        matches!(self.kind(), LLVMTypeKind::LLVMHalfTypeKind | LLVMTypeKind::LLVMBFloatTypeKind | LLVMTypeKind::LLVMFloatTypeKind |
+            LLVMTypeKind::LLVMDoubleTypeKind | LLVMTypeKind::LLVMX86_FP80TypeKind | LLVMTypeKind::LLVMFP128TypeKind |
+            LLVMTypeKind::LLVMPPC_FP128TypeKind)
+    }
+
+    pub fn is_function(&self) -> bool {
+        matches!(self.kind(), LLVMTypeKind::LLVMFunctionTypeKind)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(self.kind(), LLVMTypeKind::LLVMIntegerTypeKind)
+    }
+
+    pub fn is_pointer(&self) -> bool {
+        matches!(self.kind(), LLVMTypeKind::LLVMPointerTypeKind)
+    }
+
+    pub fn is_vector(&self) -> bool {
+        matches!(self.kind(), LLVMTypeKind::LLVMVectorTypeKind | LLVMTypeKind::LLVMScalableVectorTypeKind)
+    }
+
+    fn kind(&self) -> LLVMTypeKind {
+        unsafe {
+            LLVMGetTypeKind(self.as_raw())
+        }
+    }
+
     pub fn to_string(&self) -> String {
         unsafe {
             let cstring = LLVMPrintTypeToString(self.as_raw());
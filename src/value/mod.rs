@@ -1,14 +1,22 @@
 pub mod constant;
+pub mod typed;
 
 use std::ffi::CString;
 
-use {BasicBlock, types::Type};
+use {BasicBlock, Instruction, types::Type};
 use ffi::{
     LLVMAddIncoming,
+    LLVMCountIncoming,
+    LLVMGetIncomingBlock,
+    LLVMGetIncomingValue,
+    LLVMGetValueName2,
+    LLVMIsAInstruction,
+    LLVMIsAPHINode,
     LLVMSetValueName2,
     LLVMTypeOf,
     LLVMValueRef,
 };
+use string_from_raw_parts;
 
 #[derive(Clone, Debug)]
 pub struct Value(LLVMValueRef);
@@ -18,8 +26,8 @@ impl Value {
         Value(value_ref)
     }
 
-    // TODO: change the API so that Builder::phi() takes this array (like the OCaml binding)?
     pub fn add_incoming(&self, incoming: &[(&Value, &BasicBlock)]) {
+        debug_assert!(self.is_phi(), "add_incoming() should be called on a phi node");
         let mut incoming_values: Vec<_> = incoming.iter().map(|(value, _)| value.as_raw()).collect();
         let mut incoming_blocks: Vec<_> = incoming.iter().map(|(_, block)| block.as_raw()).collect();
         unsafe {
@@ -27,16 +35,66 @@ impl Value {
         }
     }
 
+    pub fn as_instruction(&self) -> Option<Instruction> {
+        unsafe {
+            if LLVMIsAInstruction(self.as_raw()).is_null() {
+                return None;
+            }
+            Some(Instruction::from_raw(self.as_raw()))
+        }
+    }
+
     pub fn as_raw(&self) -> LLVMValueRef {
         self.0
     }
 
+    pub fn get_name(&self) -> String {
+        unsafe {
+            let mut len = 0;
+            let name = LLVMGetValueName2(self.as_raw(), &mut len);
+            string_from_raw_parts(name, len)
+        }
+    }
+
     pub fn get_type(&self) -> Type {
         unsafe {
             Type::from_raw(LLVMTypeOf(self.as_raw()))
         }
     }
 
+    pub fn incoming(&self) -> Vec<(Value, BasicBlock)> {
+        (0..self.incoming_count())
+            .map(|index| (self.incoming_value(index), self.incoming_block(index)))
+            .collect()
+    }
+
+    pub fn incoming_block(&self, index: usize) -> BasicBlock {
+        assert!(index < self.incoming_count(), "incoming index out of bounds");
+        unsafe {
+            BasicBlock::from_raw(LLVMGetIncomingBlock(self.as_raw(), index as u32))
+        }
+    }
+
+    pub fn incoming_count(&self) -> usize {
+        assert!(self.is_phi(), "incoming values are only available on phi nodes");
+        unsafe {
+            LLVMCountIncoming(self.as_raw()) as usize
+        }
+    }
+
+    pub fn incoming_value(&self, index: usize) -> Value {
+        assert!(index < self.incoming_count(), "incoming index out of bounds");
+        unsafe {
+            Value::from_raw(LLVMGetIncomingValue(self.as_raw(), index as u32))
+        }
+    }
+
+    pub fn is_phi(&self) -> bool {
+        unsafe {
+            !LLVMIsAPHINode(self.as_raw()).is_null()
+        }
+    }
+
     pub fn set_name(&self, name: &str) {
         let cstring = CString::new(name).expect("cstring");
         unsafe {
@@ -0,0 +1,214 @@
+use ffi::{
+    LLVMGetIntTypeWidth,
+    LLVMGetVectorSize,
+    LLVMGlobalGetValueType,
+    LLVMIsAFunction,
+    LLVMTypeOf,
+    LLVMValueRef,
+};
+use module::Function;
+use types::Type;
+use Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntValue(LLVMValueRef);
+
+impl IntValue {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        if value.get_type().is_integer() {
+            Some(IntValue(value.as_raw()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid value of integer type.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        IntValue(value)
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn bit_width(&self) -> usize {
+        unsafe {
+            LLVMGetIntTypeWidth(LLVMTypeOf(self.as_raw())) as usize
+        }
+    }
+
+    pub fn get_type(&self) -> Type {
+        self.as_value().get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatValue(LLVMValueRef);
+
+impl FloatValue {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        if value.get_type().is_floating_point() {
+            Some(FloatValue(value.as_raw()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid value of floating-point type.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        FloatValue(value)
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn get_type(&self) -> Type {
+        self.as_value().get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointerValue(LLVMValueRef);
+
+impl PointerValue {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        if value.get_type().is_pointer() {
+            Some(PointerValue(value.as_raw()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid value of pointer type.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        PointerValue(value)
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn get_type(&self) -> Type {
+        self.as_value().get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionValue(LLVMValueRef);
+
+impl FunctionValue {
+    pub fn from_function(function: &Function) -> Self {
+        FunctionValue(function.as_raw())
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        unsafe {
+            if LLVMIsAFunction(value.as_raw()).is_null() {
+                return None;
+            }
+            Some(FunctionValue(value.as_raw()))
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid function.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        FunctionValue(value)
+    }
+
+    pub fn as_function(&self) -> Function {
+        unsafe {
+            Function::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn function_type(&self) -> Type {
+        unsafe {
+            Type::from_raw(LLVMGlobalGetValueType(self.as_raw()))
+        }
+    }
+
+    pub fn get_type(&self) -> Type {
+        self.as_value().get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorValue(LLVMValueRef);
+
+impl VectorValue {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        if value.get_type().is_vector() {
+            Some(VectorValue(value.as_raw()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `value` must be a valid value of vector type.
+    pub unsafe fn from_raw(value: LLVMValueRef) -> Self {
+        VectorValue(value)
+    }
+
+    pub fn as_raw(&self) -> LLVMValueRef {
+        self.0
+    }
+
+    pub fn as_value(&self) -> Value {
+        unsafe {
+            Value::from_raw(self.as_raw())
+        }
+    }
+
+    pub fn get_type(&self) -> Type {
+        self.as_value().get_type()
+    }
+
+    pub fn size(&self) -> usize {
+        unsafe {
+            LLVMGetVectorSize(LLVMTypeOf(self.as_raw())) as usize
+        }
+    }
+}
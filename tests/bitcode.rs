@@ -0,0 +1,70 @@
+extern crate rlvm;
+
+use std::env;
+use std::fs;
+use std::process;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    MemoryBuffer,
+    Module,
+    llvm_init,
+};
+use rlvm::types;
+
+fn ir_without_module_id(module: &Module) -> String {
+    module.print_to_string()
+        .lines()
+        .filter(|line| !line.starts_with("; ModuleID"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn build_sum_module(context: &Context) -> Module {
+    let module = context.new_module("module");
+    let param_types = [context.int32(), context.int32()];
+    let function_type = types::function::new(context.int32(), &param_types, false);
+    let sum = module.add_function("sum", function_type);
+    let entry = BasicBlock::append_in_context(context, &sum, "entry");
+
+    let builder = Builder::new_in_context(context);
+    builder.position_at_end(&entry);
+    let temp = builder.add(&sum.get_param(0), &sum.get_param(1), "temp");
+    builder.ret(&temp);
+    module
+}
+
+#[test]
+fn bitcode_file_round_trip() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = build_sum_module(&context);
+
+    let path = env::temp_dir().join(format!("rlvm-bitcode-{}.bc", process::id()));
+    let filename = path.to_str().expect("temporary filename");
+    module.write_bitcode_to_file(filename).expect("write bitcode");
+
+    let buffer = MemoryBuffer::from_file(filename).expect("read bitcode");
+    fs::remove_file(&path).expect("remove bitcode file");
+    let parsed_module = context.parse_bitcode(&buffer).expect("parse bitcode");
+
+    assert_eq!(ir_without_module_id(&module), ir_without_module_id(&parsed_module));
+}
+
+#[test]
+fn bitcode_memory_round_trip() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = build_sum_module(&context);
+
+    let buffer = module.write_bitcode_to_memory();
+    let parsed_module = context.parse_bitcode(&buffer).expect("parse bitcode");
+    assert_eq!(ir_without_module_id(&module), ir_without_module_id(&parsed_module));
+
+    let lazy_module = context.get_bitcode_module(buffer).expect("lazy bitcode module");
+    assert!(lazy_module.get_named_function("sum").is_some());
+}
@@ -0,0 +1,111 @@
+extern crate rlvm;
+
+use std::ptr;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    Metadata,
+    Module,
+    llvm_init,
+};
+use rlvm::ffi::{LLVMDIBuilderCreateDebugLocation, LLVMGetSubprogram};
+use rlvm::module::Function;
+
+const IR: &str = r#"
+define i32 @function(i32 %x) !dbg !4 {
+entry:
+  %a = add i32 %x, 1
+  %b = add i32 %x, 2
+  ret i32 %b
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!2}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "rlvm", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug)
+!1 = !DIFile(filename: "test.c", directory: "/")
+!2 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = distinct !DISubprogram(name: "function", scope: !1, file: !1, line: 1, type: !5, unit: !0, spFlags: DISPFlagDefinition)
+!5 = !DISubroutineType(types: !6)
+!6 = !{null}
+"#;
+
+fn parse_function(context: &Context) -> (Module, Function) {
+    let module = context.parse_ir(IR).expect("parse IR");
+    let function = module.get_named_function("function").expect("function");
+    (module, function)
+}
+
+fn debug_location(context: &Context, function: &Function, line: u32) -> Metadata {
+    unsafe {
+        let scope = LLVMGetSubprogram(function.as_raw());
+        Metadata::from_raw(LLVMDIBuilderCreateDebugLocation(context.as_raw(), line, 1, scope, ptr::null_mut()))
+    }
+}
+
+#[test]
+fn guard_restores_position_and_debug_location() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (_module, function) = parse_function(&context);
+    let entry = function.get_entry_basic_block();
+    let other = BasicBlock::append_in_context(&context, &function, "other");
+    let second = entry.instructions().nth(1).expect("second instruction");
+    let location = debug_location(&context, &function, 2);
+
+    let builder = Builder::new_in_context(&context);
+    builder.position_before(&second);
+    builder.set_current_debug_location(Some(&location));
+    {
+        let _guard = builder.save_insertion_point();
+        builder.position_at_end(&other);
+        builder.set_current_debug_location(Some(&debug_location(&context, &function, 3)));
+    }
+    assert_eq!(builder.get_insert_block(), Some(entry.clone()));
+    assert_eq!(builder.get_current_debug_location().map(|location| location.as_raw()), Some(location.as_raw()));
+
+    // The builder inserts before the saved instruction.
+    let inserted = builder.add(&function.get_param(0), &function.get_param(0), "inserted");
+    assert_eq!(inserted.as_instruction().and_then(|instruction| instruction.next()), Some(second));
+}
+
+#[test]
+fn guard_restores_missing_debug_location() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (_module, function) = parse_function(&context);
+    let builder = Builder::new_in_context(&context);
+    builder.position_at_end(&function.get_entry_basic_block());
+    {
+        let _guard = builder.save_insertion_point();
+        builder.set_current_debug_location(Some(&debug_location(&context, &function, 3)));
+    }
+    assert!(builder.get_current_debug_location().is_none());
+}
+
+#[test]
+fn guard_falls_back_to_block_end_when_instruction_moves() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (_module, function) = parse_function(&context);
+    let entry = function.get_entry_basic_block();
+    let other = BasicBlock::append_in_context(&context, &function, "other");
+    let first = entry.get_first_instruction().expect("first instruction");
+
+    let builder = Builder::new_in_context(&context);
+    builder.position_before(&first);
+    {
+        let _guard = builder.save_insertion_point();
+        builder.position_at_end(&other);
+        let terminator = builder.ret(&function.get_param(0)).as_instruction().expect("ret");
+        first.move_before(&terminator);
+    }
+    assert_eq!(builder.get_insert_block(), Some(entry.clone()));
+    let inserted = builder.add(&function.get_param(0), &function.get_param(0), "inserted");
+    assert_eq!(entry.last_instruction().map(|instruction| instruction.as_raw()), Some(inserted.as_raw()));
+}
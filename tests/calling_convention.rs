@@ -0,0 +1,23 @@
+extern crate rlvm;
+
+use rlvm::{
+    CallingConvention,
+    Context,
+    llvm_init,
+};
+
+#[test]
+fn calling_convention_round_trip() {
+    let _llvm = llvm_init();
+
+    assert_eq!(CallingConvention::new(8), CallingConvention::Fast);
+    assert_eq!(CallingConvention::new(1000), CallingConvention::Other(1000));
+
+    let context = Context::new();
+    let module = context.parse_ir("declare void @function()\n").expect("parse IR");
+    let function = module.get_named_function("function").expect("function");
+    for &calling_convention in &[CallingConvention::new(8), CallingConvention::X86RegCall, CallingConvention::new(1000)] {
+        function.set_call_conv(calling_convention);
+        assert_eq!(function.call_conv(), calling_convention);
+    }
+}
@@ -0,0 +1,84 @@
+extern crate rlvm;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    ExecutionEngine,
+    IntPredicate,
+    Module,
+    SsaBuilder,
+    Value,
+    Variable,
+    VerifierFailureAction,
+    llvm_init,
+};
+use rlvm::value::constant;
+use rlvm::types;
+
+fn int32(value: u64) -> Value {
+    constant::int(types::int32(), value, false)
+}
+
+// Build a function taking n which sums the result of add(builder, sum, i) for i in 0..n.
+fn build_sum<F>(module: &Module, name: &str, add: F)
+where F: Fn(&Builder, &Variable, &Value),
+{
+    let function_type = types::function::new(types::int32(), &[types::int32()], false);
+    let function = module.add_function(name, function_type);
+    let builder = Builder::new();
+    builder.position_at_end(&BasicBlock::append(&function, "entry"));
+
+    let ssa = SsaBuilder::new();
+    let sum = ssa.new_variable(types::int32(), "sum");
+    let index = ssa.new_variable(types::int32(), "index");
+    sum.set(&builder, &int32(0));
+    index.set(&builder, &int32(0));
+
+    builder.build_loop(|builder, loop_| {
+        let done = builder.icmp(IntPredicate::Equal, &index.get(builder), &function.get_param(0), "done");
+        loop_.build_break_if(builder, &done);
+        add(builder, &sum, &index.get(builder));
+        index.set(builder, &builder.add(&index.get(builder), &int32(1), "next"));
+    });
+
+    // The result is read before finalize(), which removes the trivial phis.
+    let result = sum.get(&builder);
+    ssa.finalize();
+    builder.ret(&result);
+}
+
+fn is_odd(builder: &Builder, value: &Value) -> Value {
+    let bit = builder.and(value, &int32(1), "bit");
+    builder.icmp(IntPredicate::NotEqual, &bit, &int32(0), "odd")
+}
+
+#[test]
+fn structured_control_flow() {
+    let _llvm = llvm_init();
+
+    let module = Module::new_with_name("module");
+    build_sum(&module, "sum", |builder, sum, index| {
+        sum.set(builder, &builder.add(&sum.get(builder), index, "sum"));
+    });
+    build_sum(&module, "sum_odd_if_then", |builder, sum, index| {
+        builder.build_if_then(&is_odd(builder, index), |builder| {
+            sum.set(builder, &builder.add(&sum.get(builder), index, "sum"));
+        });
+    });
+    build_sum(&module, "sum_odd_if", |builder, sum, index| {
+        let value = builder.build_if(&is_odd(builder, index), |_| index.clone(), |_| int32(0), "term");
+        sum.set(builder, &builder.add(&sum.get(builder), &value, "sum"));
+    });
+    module.verify(VerifierFailureAction::ReturnStatus).expect("module verify");
+    // Printing would crash if a value handed out by the SSA builder was erased.
+    module.print_to_string();
+
+    let engine = ExecutionEngine::new_for_module(&module).expect("execution engine");
+    let call = |name: &str, n: i32| -> i32 {
+        let function: fn(i32) -> i32 = unsafe { engine.get_function_address(name).expect("function").cast1_ret() };
+        function(n)
+    };
+    assert_eq!(call("sum", 10), 45);
+    assert_eq!(call("sum_odd_if_then", 10), 25);
+    assert_eq!(call("sum_odd_if", 10), 25);
+}
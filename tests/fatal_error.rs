@@ -0,0 +1,102 @@
+extern crate rlvm;
+
+use std::env;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{self, Command};
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Module,
+    VerifierFailureAction,
+    install_fatal_error_handler,
+    llvm_init,
+};
+use rlvm::types;
+
+const CHILD_VARIABLE: &str = "RLVM_FATAL_ERROR_CHILD";
+
+fn exiting_handler(reason: &str) {
+    eprintln!("LLVM fatal error: {}", reason);
+    process::exit(2);
+}
+
+fn logging_handler(reason: &str) {
+    eprintln!("LLVM fatal error: {}", reason);
+}
+
+fn replaced_handler(_reason: &str) {
+    eprintln!("replaced handler called");
+}
+
+// Verify an invalid module with VerifierFailureAction::AbortProcess, which reports a fatal error.
+fn abort_on_invalid_module(handlers: &[fn(&str)]) {
+    let _llvm = llvm_init();
+
+    for &handler in handlers {
+        install_fatal_error_handler(handler);
+    }
+
+    let module = Module::new_with_name("module");
+    let function_type = types::function::new(types::void(), &[], false);
+    let function = module.add_function("function", function_type);
+    let builder = Builder::new();
+    // The block has no terminator.
+    builder.position_at_end(&BasicBlock::append(&function, "entry"));
+
+    let _ = module.verify(VerifierFailureAction::AbortProcess);
+    unreachable!("the verifier should have aborted");
+}
+
+// Run the test in a child process, since LLVM exits after calling the handler.
+fn run_child(test: &str, handler: &str) -> process::Output {
+    Command::new(env::current_exe().expect("test executable"))
+        .args(["--exact", test, "--nocapture", "--test-threads=1"])
+        .env(CHILD_VARIABLE, handler)
+        .output()
+        .expect("run child process")
+}
+
+#[test]
+fn handler_exits() {
+    match env::var(CHILD_VARIABLE) {
+        Ok(ref handler) if handler == "exiting" => abort_on_invalid_module(&[exiting_handler]),
+        Ok(_) => return,
+        Err(_) => (),
+    }
+
+    let output = run_child("handler_exits", "exiting");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("LLVM fatal error: Broken module found, compilation aborted!"), "{}", stderr);
+    assert_eq!(output.status.code(), Some(2), "{}", stderr);
+}
+
+#[test]
+fn llvm_aborts_after_handler() {
+    match env::var(CHILD_VARIABLE) {
+        Ok(ref handler) if handler == "logging" => abort_on_invalid_module(&[logging_handler]),
+        Ok(_) => return,
+        Err(_) => (),
+    }
+
+    let output = run_child("llvm_aborts_after_handler", "logging");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("LLVM fatal error: Broken module found, compilation aborted!"), "{}", stderr);
+    // SIGABRT: the verifier asks LLVM to abort to generate a crash diagnostic.
+    assert_eq!(output.status.signal(), Some(6), "{}", stderr);
+}
+
+#[test]
+fn replace_handler() {
+    match env::var(CHILD_VARIABLE) {
+        Ok(ref handler) if handler == "replaced" => abort_on_invalid_module(&[replaced_handler, exiting_handler]),
+        Ok(_) => return,
+        Err(_) => (),
+    }
+
+    let output = run_child("replace_handler", "replaced");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("LLVM fatal error: Broken module found, compilation aborted!"), "{}", stderr);
+    assert!(!stderr.contains("replaced handler called"), "{}", stderr);
+    assert_eq!(output.status.code(), Some(2), "{}", stderr);
+}
@@ -0,0 +1,32 @@
+extern crate rlvm;
+
+use rlvm::{Context, VerifierFailureAction, llvm_init};
+
+#[test]
+fn alias_keeps_address_space() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.parse_ir("@global = addrspace(1) global i32 0\n").expect("parse IR");
+    let global = module.get_named_global("global").expect("global");
+    let alias = module.add_alias(&context.int32(), &global.as_value(), "alias");
+    assert_eq!(alias.as_value().get_type(), global.as_value().get_type());
+    assert!(module.verify(VerifierFailureAction::ReturnStatus).is_ok());
+}
+
+#[test]
+fn ifunc_resolver_constant_expression() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.parse_ir("
+        @ifunc = ifunc void (), bitcast (i8* ()* @resolver to void ()* ()*)
+
+        define i8* @resolver() {
+            ret i8* null
+        }
+    ").expect("parse IR");
+    let ifunc = module.ifuncs().next().expect("ifunc");
+    let resolver = ifunc.resolver().expect("resolver");
+    assert!(module.get_named_function("resolver").expect("resolver function").as_raw() != resolver.as_raw());
+}
@@ -0,0 +1,43 @@
+extern crate rlvm;
+
+use std::thread;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    ExecutionEngine,
+    VerifierFailureAction,
+    llvm_init,
+};
+use rlvm::types;
+use rlvm::value::constant;
+
+// Each thread initializes LLVM and compiles a function returning its argument plus the thread index.
+fn compile_and_run(index: i32) -> i32 {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    let function_type = types::function::new(context.int32(), &[context.int32()], false);
+    let function = module.add_function("add_index", function_type);
+    let builder = Builder::new_in_context(&context);
+    builder.position_at_end(&BasicBlock::append_in_context(&context, &function, "entry"));
+    let constant = constant::int(context.int32(), index as u64, false);
+    builder.ret(&builder.add(&function.get_param(0), &constant, "sum"));
+    module.verify(VerifierFailureAction::ReturnStatus).expect("module verify");
+
+    let engine = ExecutionEngine::new_for_module(&module).expect("execution engine");
+    let add_index: fn(i32) -> i32 = unsafe { engine.get_function_address("add_index").expect("function").cast1_ret() };
+    add_index(100)
+}
+
+#[test]
+fn init_from_several_threads() {
+    let threads: Vec<_> = (0..8)
+        .map(|index| thread::spawn(move || compile_and_run(index)))
+        .collect();
+    for (index, thread) in threads.into_iter().enumerate() {
+        assert_eq!(thread.join().expect("thread"), 100 + index as i32);
+    }
+}
@@ -0,0 +1,75 @@
+extern crate rlvm;
+
+use rlvm::{
+    Context,
+    Instruction,
+    Module,
+    llvm_init,
+};
+
+fn parse_function(context: &Context, ir: &str) -> (Module, Vec<Instruction>) {
+    let module = context.parse_ir(ir).expect("parse IR");
+    let function = module.get_named_function("function").expect("function");
+    let instructions = function.get_entry_basic_block().instructions().collect();
+    (module, instructions)
+}
+
+fn names(instructions: &[Instruction]) -> Vec<String> {
+    instructions.iter()
+        .map(|instruction| instruction.as_value().get_name())
+        .collect()
+}
+
+const IR: &str = "
+    define i32 @function(i32 %x) {
+    entry:
+        %a = add i32 %x, 1
+        %b = add i32 %x, 2
+        %c = mul i32 %x, 3
+        ret i32 %c
+    }
+";
+
+#[test]
+fn move_before_keeps_name() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (module, instructions) = parse_function(&context, IR);
+    instructions[2].move_before(&instructions[0]);
+
+    let function = module.get_named_function("function").expect("function");
+    let moved: Vec<_> = function.get_entry_basic_block().instructions().collect();
+    assert_eq!(names(&moved), ["c", "a", "b", ""]);
+    assert!(module.print_to_string().contains("  %c = mul i32 %x, 3\n  %a = add i32 %x, 1"), "{}", module.print_to_string());
+}
+
+#[test]
+fn move_after_keeps_name() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (module, instructions) = parse_function(&context, IR);
+    instructions[0].move_after(&instructions[1]);
+    // Moving after the last instruction appends to the block.
+    instructions[1].move_after(&instructions[3]);
+
+    let function = module.get_named_function("function").expect("function");
+    let moved: Vec<_> = function.get_entry_basic_block().instructions().collect();
+    assert_eq!(names(&moved), ["a", "c", "", "b"]);
+    assert!(module.print_to_string().contains("  %a = add i32 %x, 1\n  %c = mul i32 %x, 3"), "{}", module.print_to_string());
+}
+
+#[test]
+fn move_removed_instruction() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let (module, instructions) = parse_function(&context, IR);
+    instructions[1].remove_from_parent();
+    instructions[1].move_before(&instructions[0]);
+
+    let function = module.get_named_function("function").expect("function");
+    let moved: Vec<_> = function.get_entry_basic_block().instructions().collect();
+    assert_eq!(names(&moved), ["b", "a", "c", ""]);
+}
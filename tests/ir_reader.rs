@@ -0,0 +1,61 @@
+extern crate rlvm;
+
+use std::env;
+use std::fs;
+use std::process;
+
+use rlvm::{
+    Context,
+    Error,
+    llvm_init,
+};
+
+const INVALID_IR: &str = "define i32 @function() {
+  ret i32 %nope
+}
+";
+
+#[test]
+fn parse_error_location() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    match context.parse_ir(INVALID_IR) {
+        Err(Error::ParseIR(diagnostic)) => {
+            assert_eq!(diagnostic.line, 2);
+            assert_eq!(diagnostic.column, 11);
+            assert_eq!(diagnostic.message, "use of undefined value '%nope'");
+        },
+        result => panic!("expected a parse error, got {:?}", result.map(|module| module.print_to_string())),
+    }
+}
+
+#[test]
+fn parse_file_error_location() {
+    let _llvm = llvm_init();
+
+    // The buffer name is the path of the file, which is part of the message.
+    let path = env::temp_dir().join(format!("rlvm-ir-{}.ll", process::id()));
+    fs::write(&path, INVALID_IR).expect("write IR file");
+    let context = Context::new();
+    let result = context.parse_ir_file(path.to_str().expect("path"));
+    fs::remove_file(&path).expect("remove IR file");
+    match result {
+        Err(Error::ParseIR(diagnostic)) => {
+            assert_eq!((diagnostic.line, diagnostic.column), (2, 11));
+            assert_eq!(diagnostic.message, "use of undefined value '%nope'");
+        },
+        result => panic!("expected a parse error, got {:?}", result.map(|module| module.print_to_string())),
+    }
+}
+
+#[test]
+fn parse_missing_file() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    match context.parse_ir_file("/nonexistent/rlvm/module.ll") {
+        Err(Error::Read(message)) => assert!(message.contains("No such file or directory"), "{}", message),
+        result => panic!("expected a read error, got {:?}", result.map(|module| module.print_to_string())),
+    }
+}
@@ -0,0 +1,57 @@
+extern crate rlvm;
+
+use std::sync::{Arc, Mutex};
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    Error,
+    Module,
+    llvm_init,
+};
+use rlvm::ffi::LLVMContextGetDiagnosticContext;
+use rlvm::types;
+
+fn build_module(context: &Context, name: &str, function_name: &str) -> Module {
+    let module = context.new_module(name);
+    let function_type = types::function::new(types::void(), &[], false);
+    let function = module.add_function(function_name, function_type);
+    let builder = Builder::new_in_context(context);
+    builder.position_at_end(&BasicBlock::append_in_context(context, &function, "entry"));
+    builder.ret_no_value();
+    module
+}
+
+#[test]
+fn link_modules() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = build_module(&context, "first", "first");
+    module.link_in(build_module(&context, "second", "second")).expect("link modules");
+    assert!(module.get_named_function("first").is_some());
+    assert!(module.get_named_function("second").is_some());
+}
+
+#[test]
+fn link_error_message() {
+    let _llvm = llvm_init();
+
+    let mut context = Context::new();
+    let diagnostics = Arc::new(Mutex::new(vec![]));
+    let handler_diagnostics = diagnostics.clone();
+    context.set_diagnostic_handler(move |diagnostic| handler_diagnostics.lock().expect("lock").push(diagnostic));
+
+    let handler_context = unsafe { LLVMContextGetDiagnosticContext(context.as_raw()) };
+
+    let module = build_module(&context, "first", "function");
+    match module.link_in(build_module(&context, "second", "function")) {
+        Err(Error::Link(message)) => assert!(message.contains("symbol multiply defined"), "{}", message),
+        result => panic!("expected a link error, got {:?}", result),
+    }
+    // The errors are reported through the result, not through the context handler, which is
+    // restored after linking.
+    assert!(diagnostics.lock().expect("lock").is_empty());
+    assert_eq!(unsafe { LLVMContextGetDiagnosticContext(context.as_raw()) }, handler_context);
+}
@@ -0,0 +1,68 @@
+extern crate rlvm;
+
+use rlvm::{
+    CodeGenOptLevel,
+    CodeModel,
+    Context,
+    Error,
+    PassBuilderOptions,
+    RelocMode,
+    Target,
+    get_default_target_triple,
+    llvm_init,
+};
+use rlvm::target::TargetMachine;
+
+fn native_target_machine() -> TargetMachine {
+    let triple = get_default_target_triple();
+    let target = Target::get_from_triple(&triple).expect("native target");
+    target.create_target_machine(&triple, "generic", "", CodeGenOptLevel::Default, RelocMode::Default, CodeModel::Default)
+}
+
+#[test]
+fn run_instcombine() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.parse_ir("
+        define i32 @identity(i32 %x) {
+            %y = add i32 %x, 0
+            ret i32 %y
+        }
+    ").expect("parse IR");
+    module.run_passes("instcombine", &native_target_machine(), PassBuilderOptions::new()).expect("run passes");
+    let ir = module.print_to_string();
+    assert!(!ir.contains("add i32"), "{}", ir);
+    assert!(ir.contains("ret i32 %x"), "{}", ir);
+}
+
+#[test]
+fn run_default_pipeline() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.parse_ir("
+        define i32 @load_store(i32 %x) {
+            %pointer = alloca i32
+            store i32 %x, i32* %pointer
+            %y = load i32, i32* %pointer
+            ret i32 %y
+        }
+    ").expect("parse IR");
+    module.run_passes("default<O2>", &native_target_machine(), PassBuilderOptions::default()).expect("run passes");
+    let ir = module.print_to_string();
+    assert!(!ir.contains("alloca"), "{}", ir);
+    assert!(ir.contains("ret i32 %x"), "{}", ir);
+}
+
+#[test]
+fn invalid_pipeline() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.parse_ir("define void @function() {\n  ret void\n}\n").expect("parse IR");
+    match module.run_passes("no-such-pass", &native_target_machine(), PassBuilderOptions::new()) {
+        Err(Error::RunPasses(message)) => assert!(message.contains("unknown pass name 'no-such-pass'"), "{}", message),
+        result => panic!("expected an error for an unknown pass, got {:?}", result),
+    }
+}
@@ -0,0 +1,96 @@
+extern crate rlvm;
+
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::channel;
+use std::thread;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    ThreadSafeContext,
+    ThreadSafeModule,
+    VerifierFailureAction,
+    llvm_init,
+};
+use rlvm::types;
+use rlvm::value::constant;
+
+const MODULE_COUNT: i32 = 16;
+const WORKER_COUNT: usize = 4;
+
+// Build a module with a function returning its argument multiplied by factor.
+fn build_module(context: &ThreadSafeContext, factor: i32) -> ThreadSafeModule {
+    let module = context.new_module(&format!("module{}", factor));
+    module.with_module(|module, context| {
+        let function_type = types::function::new(context.int32(), &[context.int32()], false);
+        let function = module.add_function("multiply", function_type);
+        let entry = BasicBlock::append_in_context(context, &function, "entry");
+
+        let builder = Builder::new_in_context(context);
+        builder.position_at_end(&entry);
+        let factor = constant::int(context.int32(), factor as u64, true);
+        let result = builder.mul(&function.get_param(0), &factor, "result");
+        builder.ret(&result);
+
+        module.verify(VerifierFailureAction::ReturnStatus).expect("module verify");
+    });
+    module
+}
+
+fn compile_concurrently(contexts: &[ThreadSafeContext]) {
+    let (module_sender, module_receiver) = channel::<(i32, ThreadSafeModule)>();
+    let module_receiver = Arc::new(Mutex::new(module_receiver));
+    let (result_sender, result_receiver) = channel();
+
+    let workers: Vec<_> = (0..WORKER_COUNT)
+        .map(|_| {
+            let module_receiver = module_receiver.clone();
+            let result_sender = result_sender.clone();
+            thread::spawn(move || {
+                loop {
+                    let message = module_receiver.lock().expect("receiver lock").recv();
+                    let (factor, module) = match message {
+                        Ok(message) => message,
+                        Err(_) => break,
+                    };
+                    let result = module.with_execution_engine(|engine, _context| {
+                        let multiply: fn(i32) -> i32 = unsafe { engine.get_function_address("multiply").expect("multiply function").cast1_ret() };
+                        multiply(2)
+                    }).expect("execution engine");
+                    result_sender.send((factor, result)).expect("send result");
+                }
+            })
+        })
+        .collect();
+    drop(result_sender);
+
+    for factor in 0..MODULE_COUNT {
+        let context = &contexts[factor as usize % contexts.len()];
+        module_sender.send((factor, build_module(context, factor))).expect("send module");
+    }
+    drop(module_sender);
+
+    let mut results: Vec<_> = result_receiver.iter().collect();
+    results.sort();
+    assert_eq!(results, (0..MODULE_COUNT).map(|factor| (factor, factor * 2)).collect::<Vec<_>>());
+
+    for worker in workers {
+        worker.join().expect("join worker");
+    }
+}
+
+#[test]
+fn context_per_module() {
+    let _llvm = llvm_init();
+
+    let contexts: Vec<_> = (0..MODULE_COUNT).map(|_| ThreadSafeContext::new()).collect();
+    compile_concurrently(&contexts);
+}
+
+#[test]
+fn shared_context() {
+    let _llvm = llvm_init();
+
+    // The workers compile modules of the same context, one at a time.
+    compile_concurrently(&[ThreadSafeContext::new()]);
+}
@@ -0,0 +1,130 @@
+extern crate rlvm;
+
+use rlvm::{
+    BasicBlock,
+    Builder,
+    Context,
+    Module,
+    SsaBuilder,
+    Value,
+    VerifierFailureAction,
+    llvm_init,
+};
+use rlvm::module::Function;
+use rlvm::value::constant;
+use rlvm::types;
+
+fn int32(context: &Context, value: u64) -> Value {
+    constant::int(context.int32(), value, false)
+}
+
+// Build a function where %first uses %second, which is defined after it.
+fn build_invalid_function(context: &Context, module: &Module, name: &str) -> (Function, Value) {
+    let function_type = types::function::new(context.int32(), &[context.int32()], false);
+    let function = module.add_function(name, function_type);
+    let builder = Builder::new_in_context(context);
+    builder.position_at_end(&BasicBlock::append_in_context(context, &function, "entry"));
+    let second = builder.add(&function.get_param(0), &int32(context, 1), "second");
+    builder.ret(&second);
+    builder.position_before(&second.as_instruction().expect("instruction"));
+    builder.add(&second, &int32(context, 1), "first");
+    (function, second)
+}
+
+fn build_valid_function(context: &Context, module: &Module, name: &str) -> Function {
+    let function_type = types::function::new(context.int32(), &[context.int32()], false);
+    let function = module.add_function(name, function_type);
+    let builder = Builder::new_in_context(context);
+    builder.position_at_end(&BasicBlock::append_in_context(context, &function, "entry"));
+    builder.ret(&function.get_param(0));
+    function
+}
+
+#[test]
+fn function_diagnostics() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    let (function, second) = build_invalid_function(&context, &module, "invalid");
+    let valid = build_valid_function(&context, &module, "valid");
+
+    // Only the function itself is verified.
+    assert!(valid.verify(VerifierFailureAction::ReturnStatus).is_ok());
+
+    let diagnostics = function.verify(VerifierFailureAction::ReturnStatus).expect_err("invalid function");
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.message, "Instruction does not dominate all uses!");
+    assert_eq!(diagnostic.function.as_ref().map(Function::as_raw), Some(function.as_raw()));
+    assert_eq!(diagnostic.instruction.as_ref().map(|instruction| instruction.as_raw()), Some(second.as_raw()));
+}
+
+#[test]
+fn module_diagnostics() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    build_valid_function(&context, &module, "valid");
+    let (function, second) = build_invalid_function(&context, &module, "invalid");
+
+    let diagnostics = module.verify_diagnostics().expect_err("invalid module");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].function.as_ref().map(Function::as_raw), Some(function.as_raw()));
+    assert_eq!(diagnostics[0].instruction.as_ref().map(|instruction| instruction.as_raw()), Some(second.as_raw()));
+}
+
+#[test]
+fn unmatched_diagnostics_are_not_attributed() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    // Two identical functions print the same instructions, which cannot be told apart.
+    build_invalid_function(&context, &module, "first");
+    build_invalid_function(&context, &module, "second");
+
+    let diagnostics = module.verify_diagnostics().expect_err("invalid module");
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.function.is_none() && diagnostic.instruction.is_none()));
+}
+
+#[test]
+fn debug_verify_at_finish() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    let function_type = types::function::new(context.int32(), &[], false);
+    let function = module.add_function("function", function_type);
+    let builder = Builder::new_in_context(&context);
+    builder.set_debug_verify(true);
+    let entry = BasicBlock::append_in_context(&context, &function, "entry");
+    let exit = BasicBlock::append_in_context(&context, &function, "exit");
+    builder.position_at_end(&entry);
+
+    let ssa = SsaBuilder::new();
+    let variable = ssa.new_variable(context.int32(), "variable");
+    variable.set(&builder, &int32(&context, 42));
+    builder.br(&exit);
+    builder.position_at_end(&exit);
+    // The phi created in the unsealed block has no operands until finalize(): returning it must
+    // not verify the function.
+    builder.ret(&variable.get(&builder));
+    ssa.finalize();
+    builder.finish_function(&function);
+}
+
+#[test]
+#[should_panic(expected = "function invalid is invalid:\nin function invalid: Instruction does not dominate all uses!")]
+fn debug_verify_invalid_function() {
+    let _llvm = llvm_init();
+
+    let context = Context::new();
+    let module = context.new_module("module");
+    let (function, _) = build_invalid_function(&context, &module, "invalid");
+    let builder = Builder::new_in_context(&context);
+    builder.set_debug_verify(true);
+    builder.finish_function(&function);
+}
@@ -0,0 +1,25 @@
+#include <llvm-c/Core.h>
+#include <llvm/IR/Instruction.h>
+
+// Unlike inserting with a builder, these keep the name of the instruction.
+extern "C" void LLVM_MoveInstructionAfter(LLVMValueRef Inst, LLVMValueRef MovePos) {
+    llvm::Instruction *Instruction = llvm::unwrap<llvm::Instruction>(Inst);
+    llvm::Instruction *Position = llvm::unwrap<llvm::Instruction>(MovePos);
+    if (Instruction->getParent()) {
+        Instruction->moveAfter(Position);
+    }
+    else {
+        Instruction->insertAfter(Position);
+    }
+}
+
+extern "C" void LLVM_MoveInstructionBefore(LLVMValueRef Inst, LLVMValueRef MovePos) {
+    llvm::Instruction *Instruction = llvm::unwrap<llvm::Instruction>(Inst);
+    llvm::Instruction *Position = llvm::unwrap<llvm::Instruction>(MovePos);
+    if (Instruction->getParent()) {
+        Instruction->moveBefore(Position);
+    }
+    else {
+        Instruction->insertBefore(Position);
+    }
+}
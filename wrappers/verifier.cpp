@@ -0,0 +1,15 @@
+#include <string>
+
+#include <llvm-c/Core.h>
+#include <llvm/IR/Function.h>
+#include <llvm/IR/Verifier.h>
+#include <llvm/Support/raw_ostream.h>
+
+// Like LLVMVerifyFunction, but returns the message instead of printing it.
+extern "C" LLVMBool LLVM_VerifyFunction(LLVMValueRef Fn, char **OutMessage) {
+    std::string Message;
+    llvm::raw_string_ostream Stream(Message);
+    bool Broken = llvm::verifyFunction(*llvm::unwrap<llvm::Function>(Fn), &Stream);
+    *OutMessage = LLVMCreateMessage(Stream.str().c_str());
+    return Broken;
+}